@@ -0,0 +1,29 @@
+//! HTTP body utilities.
+
+use bytes::Bytes;
+use http_body::Body as _;
+
+#[doc(no_inline)]
+pub use http_body::Body as HttpBody;
+#[doc(no_inline)]
+pub use hyper::body::Body;
+
+/// A boxed [`Body`] trait object.
+///
+/// This is used in axum as the response body type for applications. It's
+/// necessary to unify multiple response bodies types into one.
+pub type BoxBody = http_body::combinators::UnsyncBoxBody<Bytes, crate::BoxError>;
+
+/// Convert a [`http_body::Body`] into a [`BoxBody`].
+pub fn box_body<B>(body: B) -> BoxBody
+where
+    B: http_body::Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<crate::BoxError>,
+{
+    body.map_err(Into::into).boxed_unsync()
+}
+
+/// Returns an empty [`BoxBody`].
+pub fn empty() -> BoxBody {
+    box_body(http_body::Empty::new())
+}
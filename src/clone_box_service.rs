@@ -0,0 +1,96 @@
+use std::{
+    fmt,
+    task::{Context, Poll},
+};
+use tower_service::Service;
+
+/// A `Clone + Send` boxed [`Service`].
+///
+/// Used internally to erase the concrete type of the services stored inside
+/// a [`Router`](crate::Router) while still allowing them to be cloned.
+pub(crate) struct CloneBoxService<T, U, E>(
+    Box<
+        dyn CloneService<T, Response = U, Error = E, Future = BoxFuture<U, E>> + Send,
+    >,
+);
+
+type BoxFuture<U, E> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = Result<U, E>> + Send>>;
+
+impl<T, U, E> CloneBoxService<T, U, E> {
+    pub(crate) fn new<S>(inner: S) -> Self
+    where
+        S: Service<T, Response = U, Error = E> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+    {
+        let inner = Wrapper(inner);
+        CloneBoxService(Box::new(inner))
+    }
+}
+
+impl<T, U, E> Service<T> for CloneBoxService<T, U, E> {
+    type Response = U;
+    type Error = E;
+    type Future = BoxFuture<U, E>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: T) -> Self::Future {
+        self.0.call(req)
+    }
+}
+
+impl<T, U, E> Clone for CloneBoxService<T, U, E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_box())
+    }
+}
+
+impl<T, U, E> fmt::Debug for CloneBoxService<T, U, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CloneBoxService").finish()
+    }
+}
+
+trait CloneService<R>: Service<R> {
+    fn clone_box(
+        &self,
+    ) -> Box<dyn CloneService<R, Response = Self::Response, Error = Self::Error, Future = Self::Future> + Send>;
+}
+
+struct Wrapper<S>(S);
+
+impl<S, R> Service<R> for Wrapper<S>
+where
+    S: Service<R> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<S::Response, S::Error>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        Box::pin(self.0.call(req))
+    }
+}
+
+impl<S, R> CloneService<R> for Wrapper<S>
+where
+    S: Service<R> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    fn clone_box(
+        &self,
+    ) -> Box<dyn CloneService<R, Response = S::Response, Error = S::Error, Future = BoxFuture<S::Response, S::Error>> + Send>
+    {
+        Box::new(Wrapper(self.0.clone()))
+    }
+}
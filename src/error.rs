@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// Errors that can happen when using axum.
+#[derive(Debug)]
+pub struct Error(crate::BoxError);
+
+impl Error {
+    /// Create a new `Error` from a boxable error.
+    pub fn new(error: impl Into<crate::BoxError>) -> Self {
+        Self(error.into())
+    }
+
+    pub(crate) fn into_inner(self) -> crate::BoxError {
+        self.0
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.0)
+    }
+}
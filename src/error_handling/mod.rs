@@ -0,0 +1,152 @@
+//! Error handling utilities.
+//!
+//! See [`crate`] docs for more details on error handling.
+
+use crate::{
+    body::{box_body, BoxBody},
+    response::IntoResponse,
+    BoxError,
+};
+use http::{Request, Response};
+use std::{
+    convert::Infallible,
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// [`Layer`] that applies [`HandleError`] which turns a `Service`'s error type into a response.
+///
+/// See [`crate`] docs for more details on error handling.
+#[derive(Clone)]
+pub struct HandleErrorLayer<F, T> {
+    f: F,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<F, T> HandleErrorLayer<F, T> {
+    /// Create a new `HandleErrorLayer`.
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F, T> fmt::Debug for HandleErrorLayer<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HandleErrorLayer").finish()
+    }
+}
+
+impl<S, F, T> Layer<S> for HandleErrorLayer<F, T>
+where
+    F: Clone,
+{
+    type Service = HandleError<S, F, T>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HandleError::new(inner, self.f.clone())
+    }
+}
+
+/// A [`Service`] adapter that turns fallible services into ones that always produce a response,
+/// by mapping errors through a closure.
+///
+/// See [`crate`] docs for more details on error handling.
+pub struct HandleError<S, F, T> {
+    inner: S,
+    f: F,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<S, F, T> HandleError<S, F, T> {
+    /// Create a new `HandleError`.
+    pub fn new(inner: S, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, F, T> Clone for HandleError<S, F, T>
+where
+    S: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            f: self.f.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, F, T> fmt::Debug for HandleError<S, F, T>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HandleError")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<S, F, ReqBody, ResBody, Res> Service<Request<ReqBody>> for HandleError<S, F, Res>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+    F: FnOnce(BoxError) -> Res + Clone + Send + 'static,
+    Res: IntoResponse,
+    ResBody: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+    ResBody::Error: Into<BoxError>,
+    ReqBody: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let f = self.f.clone();
+
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            match inner.call(req).await {
+                Ok(res) => Ok(res.map(box_body)),
+                Err(err) => Ok(f(err.into()).into_response()),
+            }
+        })
+    }
+}
+
+/// Extension trait that adds [`handle_error`](HandleErrorExt::handle_error) to any [`Service`].
+///
+/// See [`crate`] docs for more details on error handling.
+pub trait HandleErrorExt<ReqBody>: Service<Request<ReqBody>> + Sized {
+    /// Apply an error handling function to this service.
+    fn handle_error<F, Res>(self, f: F) -> HandleError<Self, F, Res>
+    where
+        F: FnOnce(BoxError) -> Res + Clone,
+        Res: IntoResponse,
+    {
+        HandleError::new(self, f)
+    }
+}
+
+impl<S, ReqBody> HandleErrorExt<ReqBody> for S where S: Service<Request<ReqBody>> {}
@@ -0,0 +1,139 @@
+use super::{FromRequest, RequestParts};
+use async_trait::async_trait;
+use std::convert::Infallible;
+
+/// Extractor that parses the request's `Accept` header into the media ranges it offers.
+///
+/// Used by [`Negotiate`](crate::response::Negotiate) to pick which of its registered formats
+/// (JSON, and CBOR/MessagePack behind their feature flags) to respond with. An absent `Accept`
+/// header, or one containing only `*/*`, accepts everything.
+#[derive(Debug, Clone)]
+pub struct AcceptedTypes(Vec<MediaRange>);
+
+#[derive(Debug, Clone)]
+struct MediaRange {
+    mime: mime::Mime,
+    q: f32,
+    // Position in the `Accept` header, used to break ties between equally-qualified,
+    // equally-specific ranges in favor of the one the client listed first.
+    order: usize,
+}
+
+/// How precisely a media range identifies a concrete media type: an exact match beats a
+/// `type/*` wildcard, which beats a bare `*/*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Specificity {
+    Any,
+    Type,
+    Exact,
+}
+
+impl MediaRange {
+    fn specificity_against(&self, candidate: &mime::Mime) -> Option<Specificity> {
+        let type_matches = self.mime.type_() == candidate.type_();
+        let subtype_matches = self.mime.subtype() == candidate.subtype();
+
+        match (
+            type_matches || self.mime.type_() == mime::STAR,
+            subtype_matches || self.mime.subtype() == mime::STAR,
+        ) {
+            (true, true) if type_matches && subtype_matches => Some(Specificity::Exact),
+            (true, true) if type_matches => Some(Specificity::Type),
+            (true, true) => Some(Specificity::Any),
+            _ => None,
+        }
+    }
+}
+
+impl AcceptedTypes {
+    /// Returns the most preferred of `offered` that the client's `Accept` header will accept, if
+    /// any.
+    ///
+    /// Candidates are ranked by descending `q` value, then by descending specificity (an exact
+    /// match beats `type/*`, which beats `*/*`), then by the order the client listed them in the
+    /// `Accept` header. `offered` should be given in the order the caller prefers them, used as
+    /// a final tiebreak if two client ranges are otherwise equally good.
+    pub fn best_match<'a>(&self, offered: &[&'a mime::Mime]) -> Option<&'a mime::Mime> {
+        if self.0.is_empty() {
+            return offered.first().copied();
+        }
+
+        let mut ranked = self
+            .0
+            .iter()
+            .filter(|range| range.q > 0.0)
+            .collect::<Vec<_>>();
+        ranked.sort_by(|a, b| b.q.partial_cmp(&a.q).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut best: Option<(&'a mime::Mime, f32, Specificity, usize, usize)> = None;
+
+        for range in &ranked {
+            for (offered_idx, candidate) in offered.iter().enumerate() {
+                let specificity = match range.specificity_against(candidate) {
+                    Some(specificity) => specificity,
+                    None => continue,
+                };
+
+                let key = (
+                    range.q,
+                    specificity,
+                    std::cmp::Reverse(range.order),
+                    std::cmp::Reverse(offered_idx),
+                );
+
+                let is_better = match &best {
+                    None => true,
+                    Some((_, best_q, best_specificity, best_order, best_offered_idx)) => {
+                        key > (
+                            *best_q,
+                            *best_specificity,
+                            std::cmp::Reverse(*best_order),
+                            std::cmp::Reverse(*best_offered_idx),
+                        )
+                    }
+                };
+
+                if is_better {
+                    best = Some((candidate, range.q, specificity, range.order, offered_idx));
+                }
+            }
+        }
+
+        best.map(|(mime, ..)| mime)
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for AcceptedTypes
+where
+    B: Send,
+{
+    type Rejection = Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let header = req
+            .headers()
+            .and_then(|headers| headers.get(http::header::ACCEPT))
+            .and_then(|value| value.to_str().ok());
+
+        let header = match header {
+            Some(header) => header,
+            None => return Ok(Self(Vec::new())),
+        };
+
+        let offered = header
+            .split(',')
+            .enumerate()
+            .filter_map(|(order, part)| {
+                let mime: mime::Mime = part.trim().parse().ok()?;
+                let q = mime
+                    .get_param("q")
+                    .and_then(|q| q.as_str().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                Some(MediaRange { mime, q, order })
+            })
+            .collect();
+
+        Ok(Self(offered))
+    }
+}
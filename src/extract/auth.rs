@@ -0,0 +1,273 @@
+use super::{
+    rejection::{BasicRejection, BearerRejection},
+    FromRequest, RequestParts,
+};
+use crate::{body::BoxBody, response::IntoResponse};
+use async_trait::async_trait;
+use futures_util::future::BoxFuture;
+use http::Request;
+use std::{
+    fmt,
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// Extractor for a bearer token from the `Authorization: Bearer <token>` header.
+///
+/// Rejects with `401 Unauthorized` (and a `WWW-Authenticate: Bearer` header) if the header is
+/// missing or isn't a valid bearer credential.
+#[derive(Debug, Clone)]
+pub struct Bearer(String);
+
+impl Bearer {
+    /// Get the token, with the `Bearer ` prefix already stripped.
+    pub fn token(&self) -> &str {
+        &self.0
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for Bearer
+where
+    B: Send,
+{
+    type Rejection = BearerRejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let header = req
+            .headers()
+            .and_then(|headers| headers.get(http::header::AUTHORIZATION))
+            .ok_or(BearerRejection::Missing)?;
+
+        let header = header.to_str().map_err(|_| BearerRejection::Invalid)?;
+        let token = header.strip_prefix("Bearer ").ok_or(BearerRejection::Invalid)?;
+
+        if token.is_empty() {
+            return Err(BearerRejection::Invalid);
+        }
+
+        Ok(Self(token.to_owned()))
+    }
+}
+
+/// Extractor for `user:pass` credentials from the `Authorization: Basic <base64>` header.
+///
+/// Rejects with `401 Unauthorized` (and a `WWW-Authenticate: Basic` header) if the header is
+/// missing or isn't valid base64-encoded `user:pass` credentials.
+#[derive(Debug, Clone)]
+pub struct Basic {
+    username: String,
+    password: String,
+}
+
+impl Basic {
+    /// The decoded username.
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// The decoded password.
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for Basic
+where
+    B: Send,
+{
+    type Rejection = BasicRejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let header = req
+            .headers()
+            .and_then(|headers| headers.get(http::header::AUTHORIZATION))
+            .ok_or(BasicRejection::Missing)?;
+
+        let header = header.to_str().map_err(|_| BasicRejection::Invalid)?;
+        let encoded = header.strip_prefix("Basic ").ok_or(BasicRejection::Invalid)?;
+
+        let decoded = base64::decode(encoded).map_err(|_| BasicRejection::Invalid)?;
+        let decoded = String::from_utf8(decoded).map_err(|_| BasicRejection::Invalid)?;
+        let (username, password) = decoded.split_once(':').ok_or(BasicRejection::Invalid)?;
+
+        Ok(Self {
+            username: username.to_owned(),
+            password: password.to_owned(),
+        })
+    }
+}
+
+/// Trait for validating a request and producing the claims to attach to it.
+///
+/// Implement this to customize [`RequireAuthorization`]'s validation logic. For a one-off check,
+/// pass a closure `FnMut(&Request<B>) -> Result<Claims, Rejection>` to
+/// [`RequireAuthorizationLayer::custom`] instead; it implements this trait already.
+pub trait AuthorizeRequest<B> {
+    /// The claims produced by a successful authorization. Inserted into the request's extensions,
+    /// so downstream handlers can retrieve them with `Extension<Self::Claims>`.
+    type Claims: Clone + Send + Sync + 'static;
+    /// The rejection returned, and turned into a response, if authorization fails.
+    type Rejection: IntoResponse;
+
+    /// Validate `request`, returning the claims to attach to it or a rejection.
+    fn authorize(&mut self, request: &Request<B>) -> Result<Self::Claims, Self::Rejection>;
+}
+
+impl<B, F, Claims, Rejection> AuthorizeRequest<B> for F
+where
+    F: FnMut(&Request<B>) -> Result<Claims, Rejection>,
+    Claims: Clone + Send + Sync + 'static,
+    Rejection: IntoResponse,
+{
+    type Claims = Claims;
+    type Rejection = Rejection;
+
+    fn authorize(&mut self, request: &Request<B>) -> Result<Claims, Rejection> {
+        self(request)
+    }
+}
+
+/// [`Layer`] that applies [`RequireAuthorization`].
+///
+/// See [`RequireAuthorization`] for more details.
+pub struct RequireAuthorizationLayer<T> {
+    authorize: T,
+}
+
+impl<T> RequireAuthorizationLayer<T> {
+    /// Create a `RequireAuthorizationLayer` from an [`AuthorizeRequest`] implementation, or a
+    /// closure `FnMut(&Request<B>) -> Result<Claims, Rejection>`.
+    pub fn custom(authorize: T) -> Self {
+        Self { authorize }
+    }
+}
+
+impl<T: Clone> Clone for RequireAuthorizationLayer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            authorize: self.authorize.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for RequireAuthorizationLayer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequireAuthorizationLayer").finish()
+    }
+}
+
+impl<S, T> Layer<S> for RequireAuthorizationLayer<T>
+where
+    T: Clone,
+{
+    type Service = RequireAuthorization<S, T>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequireAuthorization::new(inner, self.authorize.clone())
+    }
+}
+
+/// [`Service`] that runs an [`AuthorizeRequest`] check before calling the inner service, and
+/// inserts the resulting claims into the request's extensions on success.
+///
+/// Rejects with the [`AuthorizeRequest::Rejection`] turned into a response if the check fails,
+/// without calling the inner service.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::Extension,
+///     handler::Handler,
+///     extract::{Bearer, RequireAuthorizationLayer},
+///     http::{Request, StatusCode},
+///     routing::get,
+///     Router,
+/// };
+///
+/// #[derive(Clone)]
+/// struct CurrentUser(String);
+///
+/// async fn handler(Extension(user): Extension<CurrentUser>) -> String {
+///     user.0
+/// }
+///
+/// let app = Router::new().route(
+///     "/",
+///     get(handler).layer(RequireAuthorizationLayer::custom(
+///         |req: &Request<_>| -> Result<CurrentUser, StatusCode> {
+///             // Validate `req`'s `Authorization` header and look up the user it names.
+///             # unimplemented!()
+///         },
+///     )),
+/// );
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+pub struct RequireAuthorization<S, T> {
+    inner: S,
+    authorize: T,
+}
+
+impl<S, T> RequireAuthorization<S, T> {
+    /// Create a new `RequireAuthorization`.
+    pub fn new(inner: S, authorize: T) -> Self {
+        Self { inner, authorize }
+    }
+}
+
+impl<S: Clone, T: Clone> Clone for RequireAuthorization<S, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            authorize: self.authorize.clone(),
+        }
+    }
+}
+
+impl<S, T> fmt::Debug for RequireAuthorization<S, T>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequireAuthorization")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<S, T, ReqBody> Service<Request<ReqBody>> for RequireAuthorization<S, T>
+where
+    S: Service<Request<ReqBody>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    T: AuthorizeRequest<ReqBody> + Clone + Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        let not_ready_inner = self.inner.clone();
+        let mut ready_inner = std::mem::replace(&mut self.inner, not_ready_inner);
+        let mut authorize = self.authorize.clone();
+
+        Box::pin(async move {
+            match authorize.authorize(&req) {
+                Ok(claims) => {
+                    req.extensions_mut().insert(claims);
+                    ready_inner.call(req).await
+                }
+                Err(rejection) => Ok(rejection.into_response()),
+            }
+        })
+    }
+}
@@ -0,0 +1,109 @@
+use super::{
+    rejection::{LengthRequired, PayloadTooLarge},
+    FromRequest, RequestParts,
+};
+use async_trait::async_trait;
+use http::header::CONTENT_LENGTH;
+use std::ops::{Deref, DerefMut};
+
+/// Extractor that will reject requests with a body larger than some size, or that has no
+/// `Content-Length` header.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::ContentLengthLimit,
+///     routing::post,
+///     Router,
+/// };
+///
+/// async fn upload(ContentLengthLimit(body): ContentLengthLimit<bytes::Bytes, 1024>) {
+///     // ...
+/// }
+///
+/// let app = Router::new().route("/upload", post(upload));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+///
+/// This requires the request to have a `Content-Length` header and will reject the request with
+/// `411 Length Required` if it's missing, or with `413 Payload Too Large` if it exceeds `N`.
+#[derive(Debug, Clone)]
+pub struct ContentLengthLimit<T, const N: u64>(pub T);
+
+#[async_trait]
+impl<T, B, const N: u64> FromRequest<B> for ContentLengthLimit<T, N>
+where
+    T: FromRequest<B>,
+    B: Send,
+{
+    type Rejection = ContentLengthLimitRejection<T::Rejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let content_length = req
+            .headers()
+            .and_then(|headers| headers.get(CONTENT_LENGTH))
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+
+        let content_length = content_length.ok_or(ContentLengthLimitRejection::LengthRequired(
+            LengthRequired,
+        ))?;
+
+        if content_length > N {
+            return Err(ContentLengthLimitRejection::PayloadTooLarge(
+                PayloadTooLarge,
+            ));
+        }
+
+        let value = T::from_request(req)
+            .await
+            .map_err(ContentLengthLimitRejection::Inner)?;
+
+        Ok(Self(value))
+    }
+}
+
+impl<T, const N: u64> Deref for ContentLengthLimit<T, N> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: u64> DerefMut for ContentLengthLimit<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Rejection used for [`ContentLengthLimit`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ContentLengthLimitRejection<T> {
+    /// The request didn't have a `Content-Length` header.
+    #[allow(missing_docs)]
+    LengthRequired(LengthRequired),
+    /// The request's `Content-Length` header exceeded the configured limit.
+    #[allow(missing_docs)]
+    PayloadTooLarge(PayloadTooLarge),
+    /// The inner extractor failed.
+    #[allow(missing_docs)]
+    Inner(T),
+}
+
+impl<T> crate::response::IntoResponse for ContentLengthLimitRejection<T>
+where
+    T: crate::response::IntoResponse,
+{
+    fn into_response(self) -> http::Response<crate::body::BoxBody> {
+        match self {
+            Self::LengthRequired(inner) => inner.into_response(),
+            Self::PayloadTooLarge(inner) => inner.into_response(),
+            Self::Inner(inner) => inner.into_response(),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::{rejection::ExtensionRejection, FromRequest, RequestParts};
+use async_trait::async_trait;
+use std::ops::{Deref, DerefMut};
+
+/// Extractor for extensions.
+///
+/// Extensions are shared state that was inserted either by middleware (see
+/// [`AddExtensionLayer`](crate::AddExtensionLayer)) or previously inside a handler using
+/// [`RequestParts::extensions_mut`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Extension<T>(pub T);
+
+#[async_trait]
+impl<T, B> FromRequest<B> for Extension<T>
+where
+    T: Clone + Send + Sync + 'static,
+    B: Send,
+{
+    type Rejection = ExtensionRejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let value = req
+            .extensions()
+            .and_then(|extensions| extensions.get::<T>())
+            .cloned()
+            .ok_or(ExtensionRejection)?;
+
+        Ok(Extension(value))
+    }
+}
+
+impl<T> Deref for Extension<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Extension<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,157 @@
+use super::{FromRequest, RequestParts};
+use crate::body::BoxBody;
+use futures_util::future::BoxFuture;
+use http::Request;
+use std::{
+    fmt,
+    marker::PhantomData,
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// Create a middleware from an extractor.
+///
+/// If the extractor succeeds the value is discarded and the inner service is called. If it
+/// fails the rejection is returned as a response.
+///
+/// This is commonly used to run some kind of authentication logic as middleware without having
+/// to wrap every handler that needs it with an extractor.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::{extractor_middleware, FromRequest, RequestParts},
+///     routing::get,
+///     handler::Handler,
+///     http::StatusCode,
+///     Router,
+/// };
+///
+/// struct RequireAuth;
+///
+/// #[axum::async_trait]
+/// impl<B> FromRequest<B> for RequireAuth
+/// where
+///     B: Send,
+/// {
+///     type Rejection = StatusCode;
+///
+///     async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+///         // ...
+///         # unimplemented!()
+///     }
+/// }
+///
+/// async fn handler() {}
+///
+/// let app = Router::new().route(
+///     "/",
+///     get(handler.layer(extractor_middleware::<RequireAuth>())),
+/// );
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+pub fn extractor_middleware<E>() -> ExtractorMiddlewareLayer<E> {
+    ExtractorMiddlewareLayer(PhantomData)
+}
+
+/// [`Layer`] that applies [`ExtractorMiddleware`] that runs an extractor and discards the value.
+///
+/// See [`extractor_middleware`] for more details.
+pub struct ExtractorMiddlewareLayer<E>(PhantomData<fn() -> E>);
+
+impl<E> Clone for ExtractorMiddlewareLayer<E> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E> fmt::Debug for ExtractorMiddlewareLayer<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtractorMiddlewareLayer").finish()
+    }
+}
+
+impl<E, S> Layer<S> for ExtractorMiddlewareLayer<E> {
+    type Service = ExtractorMiddleware<S, E>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ExtractorMiddleware {
+            inner,
+            _extractor: PhantomData,
+        }
+    }
+}
+
+/// Middleware that runs an extractor and discards the value, returning the extractor's
+/// rejection as a response if it fails.
+///
+/// See [`extractor_middleware`] for more details.
+pub struct ExtractorMiddleware<S, E> {
+    inner: S,
+    _extractor: PhantomData<fn() -> E>,
+}
+
+impl<S, E> Clone for ExtractorMiddleware<S, E>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _extractor: PhantomData,
+        }
+    }
+}
+
+impl<S, E> fmt::Debug for ExtractorMiddleware<S, E>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtractorMiddleware")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<ReqBody, E, S> Service<Request<ReqBody>> for ExtractorMiddleware<S, E>
+where
+    E: FromRequest<ReqBody> + 'static,
+    S: Service<Request<ReqBody>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let not_ready_inner = self.inner.clone();
+        let mut ready_inner = std::mem::replace(&mut self.inner, not_ready_inner);
+
+        Box::pin(async move {
+            let mut req = RequestParts::new(req);
+
+            match E::from_request(&mut req).await {
+                Ok(_) => {
+                    let req = req
+                        .try_into_request()
+                        .unwrap_or_else(|err| unreachable!("{}", err));
+                    ready_inner.call(req).await
+                }
+                Err(rejection) => {
+                    use crate::response::IntoResponse;
+                    Ok(rejection.into_response())
+                }
+            }
+        })
+    }
+}
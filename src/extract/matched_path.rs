@@ -0,0 +1,56 @@
+use super::{FromRequest, RequestParts};
+use async_trait::async_trait;
+use std::{convert::Infallible, sync::Arc};
+
+/// Extractor that returns the path the matched route was registered as, for the handler's
+/// current request.
+///
+/// This will include parameter names so e.g. `/users/:id` won't be replaced with `/users/123`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::MatchedPath,
+///     routing::get,
+///     Router,
+/// };
+///
+/// let app = Router::new().route(
+///     "/users/:id",
+///     get(|path: MatchedPath| async move {
+///         let path = path.as_str();
+///         // `path` will be "/users/:id"
+///     }),
+/// );
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+#[derive(Clone, Debug)]
+pub struct MatchedPath(pub(crate) Arc<str>);
+
+impl MatchedPath {
+    /// Returns a `str` representation of the path.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for MatchedPath
+where
+    B: Send,
+{
+    type Rejection = Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let matched_path = req
+            .extensions()
+            .and_then(|ext| ext.get::<Self>())
+            .cloned()
+            .unwrap_or_else(|| MatchedPath(Arc::from(req.uri().path())));
+
+        Ok(matched_path)
+    }
+}
@@ -0,0 +1,212 @@
+//! Types and traits for extracting data from requests.
+//!
+//! See [`crate`] docs for more details.
+
+use async_trait::async_trait;
+use http::{Extensions, HeaderMap, Method, Request, Uri, Version};
+use std::convert::Infallible;
+
+pub mod rejection;
+
+#[cfg(feature = "json")]
+mod accept;
+#[cfg(feature = "auth")]
+mod auth;
+mod content_length_limit;
+mod extension;
+mod extractor_middleware;
+mod matched_path;
+mod path;
+mod query;
+
+#[cfg(feature = "json")]
+pub use self::accept::AcceptedTypes;
+#[cfg(feature = "auth")]
+pub use self::auth::{AuthorizeRequest, Basic, Bearer, RequireAuthorization, RequireAuthorizationLayer};
+#[cfg(feature = "json")]
+pub use crate::json::Json;
+pub use content_length_limit::ContentLengthLimit;
+pub use extension::Extension;
+pub use extractor_middleware::extractor_middleware;
+pub use matched_path::MatchedPath;
+pub use path::Path;
+pub(crate) use path::UrlParams;
+pub use query::Query;
+
+/// Types that can be created from requests.
+///
+/// See [`crate`] docs for more details.
+#[async_trait]
+pub trait FromRequest<B>: Sized {
+    /// If the extractor fails it'll use this "rejection" type. A rejection is
+    /// a kind of error that can be converted into a response.
+    type Rejection: crate::response::IntoResponse;
+
+    /// Perform the extraction.
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection>;
+}
+
+/// The parts of a request that hasn't been consumed yet, plus its body, each
+/// of which can be extracted exactly once.
+#[derive(Debug)]
+pub struct RequestParts<B> {
+    method: Method,
+    uri: Uri,
+    version: Version,
+    headers: Option<HeaderMap>,
+    extensions: Option<Extensions>,
+    body: Option<B>,
+}
+
+impl<B> RequestParts<B> {
+    /// Create a new `RequestParts` from a [`Request`].
+    pub fn new(req: Request<B>) -> Self {
+        let (
+            http::request::Parts {
+                method,
+                uri,
+                version,
+                headers,
+                extensions,
+                ..
+            },
+            body,
+        ) = req.into_parts();
+
+        RequestParts {
+            method,
+            uri,
+            version,
+            headers: Some(headers),
+            extensions: Some(extensions),
+            body: Some(body),
+        }
+    }
+
+    /// Gets a reference to the request method.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Gets a reference to the request URI.
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    /// Get the request's HTTP version.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Gets a reference to the request headers, if they haven't already been extracted.
+    pub fn headers(&self) -> Option<&HeaderMap> {
+        self.headers.as_ref()
+    }
+
+    /// Gets a mutable reference to the request headers, if they haven't already been extracted.
+    pub fn headers_mut(&mut self) -> Option<&mut HeaderMap> {
+        self.headers.as_mut()
+    }
+
+    /// Takes the request headers, if they haven't already been extracted.
+    pub fn take_headers(&mut self) -> Option<HeaderMap> {
+        self.headers.take()
+    }
+
+    /// Gets a reference to the request extensions, if they haven't already been extracted.
+    pub fn extensions(&self) -> Option<&Extensions> {
+        self.extensions.as_ref()
+    }
+
+    /// Gets a mutable reference to the request extensions, if they haven't already been
+    /// extracted.
+    pub fn extensions_mut(&mut self) -> Option<&mut Extensions> {
+        self.extensions.as_mut()
+    }
+
+    /// Takes the request extensions, if they haven't already been extracted.
+    pub fn take_extensions(&mut self) -> Option<Extensions> {
+        self.extensions.take()
+    }
+
+    /// Gets a reference to the request body, if it hasn't already been extracted.
+    pub fn body(&self) -> Option<&B> {
+        self.body.as_ref()
+    }
+
+    /// Gets a mutable reference to the request body, if it hasn't already been extracted.
+    pub fn body_mut(&mut self) -> Option<&mut B> {
+        self.body.as_mut()
+    }
+
+    /// Takes the body out of the request, leaving a `None` in its place.
+    pub fn take_body(&mut self) -> Option<B> {
+        self.body.take()
+    }
+
+    /// Convert this `RequestParts` back into a [`Request`].
+    pub fn try_into_request(self) -> Result<Request<B>, rejection::BodyAlreadyExtracted> {
+        let Self {
+            method,
+            uri,
+            version,
+            mut headers,
+            mut extensions,
+            mut body,
+        } = self;
+
+        let mut req = Request::new(body.take().ok_or(rejection::BodyAlreadyExtracted)?);
+
+        *req.method_mut() = method;
+        *req.uri_mut() = uri;
+        *req.version_mut() = version;
+
+        if let Some(headers) = headers.take() {
+            *req.headers_mut() = headers;
+        }
+
+        if let Some(extensions) = extensions.take() {
+            *req.extensions_mut() = extensions;
+        }
+
+        Ok(req)
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for ()
+where
+    B: Send,
+{
+    type Rejection = Infallible;
+
+    async fn from_request(_: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T, B> FromRequest<B> for Option<T>
+where
+    T: FromRequest<B>,
+    B: Send,
+{
+    type Rejection = Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(T::from_request(req).await.ok())
+    }
+}
+
+#[async_trait]
+impl<T, B> FromRequest<B> for Result<T, T::Rejection>
+where
+    T: FromRequest<B>,
+    B: Send,
+{
+    type Rejection = Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(T::from_request(req).await)
+    }
+}
@@ -0,0 +1,304 @@
+use serde::{
+    de::{self, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, VariantAccess, Visitor},
+    forward_to_deserialize_any, Deserializer,
+};
+use std::fmt;
+
+/// A deserializer that pulls values out of a list of captured `(name, value)` path segments.
+///
+/// Supports deserializing into a single value (`Path<i32>`, `Path<String>`, `Path<Uuid>`, ...)
+/// when there's exactly one capture, a tuple (`Path<(String, i32)>`) in capture order, or a
+/// struct/map (`Path<Params>`, `Path<HashMap<String, String>>`) keyed by capture name.
+pub(super) struct PathDeserializer<'de> {
+    params: &'de [(String, String)],
+}
+
+impl<'de> PathDeserializer<'de> {
+    pub(super) fn new(params: &'de [(String, String)]) -> Self {
+        PathDeserializer { params }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct PathDeserializationError {
+    msg: String,
+}
+
+impl PathDeserializationError {
+    pub(super) fn new(msg: impl Into<String>) -> Self {
+        Self { msg: msg.into() }
+    }
+}
+
+impl fmt::Display for PathDeserializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.msg)
+    }
+}
+
+impl std::error::Error for PathDeserializationError {}
+
+impl de::Error for PathDeserializationError {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        Self::new(msg.to_string())
+    }
+}
+
+macro_rules! parse_single_value {
+    ($trait_fn:ident, $visit_fn:ident, $ty:literal) => {
+        fn $trait_fn<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            if self.params.len() != 1 {
+                return Err(PathDeserializationError::new(format!(
+                    "wrong number of path arguments for `{}`. Expected 1 but got {}",
+                    $ty,
+                    self.params.len()
+                )));
+            }
+
+            let value = &self.params[0].1;
+            visitor.$visit_fn(value.parse().map_err(|_| {
+                PathDeserializationError::new(format!(
+                    "failed to parse path param {:?} as `{}`",
+                    value, $ty
+                ))
+            })?)
+        }
+    };
+}
+
+impl<'de> Deserializer<'de> for PathDeserializer<'de> {
+    type Error = PathDeserializationError;
+
+    parse_single_value!(deserialize_bool, visit_bool, "bool");
+    parse_single_value!(deserialize_i8, visit_i8, "i8");
+    parse_single_value!(deserialize_i16, visit_i16, "i16");
+    parse_single_value!(deserialize_i32, visit_i32, "i32");
+    parse_single_value!(deserialize_i64, visit_i64, "i64");
+    parse_single_value!(deserialize_u8, visit_u8, "u8");
+    parse_single_value!(deserialize_u16, visit_u16, "u16");
+    parse_single_value!(deserialize_u32, visit_u32, "u32");
+    parse_single_value!(deserialize_u64, visit_u64, "u64");
+    parse_single_value!(deserialize_f32, visit_f32, "f32");
+    parse_single_value!(deserialize_f64, visit_f64, "f64");
+    parse_single_value!(deserialize_char, visit_char, "char");
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.params.len() != 1 {
+            return Err(PathDeserializationError::new(format!(
+                "wrong number of path arguments. Expected 1 but got {}",
+                self.params.len()
+            )));
+        }
+
+        visitor.visit_str(&self.params[0].1)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(ParamsMapAccess {
+            params: self.params,
+            idx: 0,
+        })
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_tuple(self.params.len(), visitor)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.params.len() != len {
+            return Err(PathDeserializationError::new(format!(
+                "wrong number of path arguments. Expected {} but got {}",
+                len,
+                self.params.len()
+            )));
+        }
+
+        struct SeqAccess<'de> {
+            params: &'de [(String, String)],
+            idx: usize,
+        }
+
+        impl<'de> de::SeqAccess<'de> for SeqAccess<'de> {
+            type Error = PathDeserializationError;
+
+            fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+            where
+                T: DeserializeSeed<'de>,
+            {
+                if self.idx >= self.params.len() {
+                    return Ok(None);
+                }
+                let value = &self.params[self.idx].1;
+                self.idx += 1;
+                seed.deserialize(value.as_str().into_deserializer()).map(Some)
+            }
+        }
+
+        visitor.visit_seq(SeqAccess {
+            params: self.params,
+            idx: 0,
+        })
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.params.len() != 1 {
+            return Err(PathDeserializationError::new(
+                "wrong number of path arguments for enum",
+            ));
+        }
+        visitor.visit_enum(EnumDeserializer {
+            value: &self.params[0].1,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bytes byte_buf unit unit_struct newtype_struct
+        tuple_struct identifier ignored_any
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+}
+
+struct ParamsMapAccess<'de> {
+    params: &'de [(String, String)],
+    idx: usize,
+}
+
+impl<'de> MapAccess<'de> for ParamsMapAccess<'de> {
+    type Error = PathDeserializationError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.idx >= self.params.len() {
+            return Ok(None);
+        }
+        seed.deserialize(self.params[self.idx].0.as_str().into_deserializer())
+            .map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = seed.deserialize(self.params[self.idx].1.as_str().into_deserializer());
+        self.idx += 1;
+        value
+    }
+}
+
+struct EnumDeserializer<'de> {
+    value: &'de str,
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer<'de> {
+    type Error = PathDeserializationError;
+    type Variant = UnitOnlyVariantAccess;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(self.value.into_deserializer())?;
+        Ok((variant, UnitOnlyVariantAccess))
+    }
+}
+
+struct UnitOnlyVariantAccess;
+
+impl<'de> VariantAccess<'de> for UnitOnlyVariantAccess {
+    type Error = PathDeserializationError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, _seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        Err(PathDeserializationError::new(
+            "newtype enum variants are not supported in path params",
+        ))
+    }
+
+    fn tuple_variant<V>(self, _len: usize, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(PathDeserializationError::new(
+            "tuple enum variants are not supported in path params",
+        ))
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(PathDeserializationError::new(
+            "struct enum variants are not supported in path params",
+        ))
+    }
+}
@@ -0,0 +1,98 @@
+//! Extractor that will get captured parameters from the URL and decode them.
+
+use super::{rejection::FailedToDeserializePathParams, FromRequest, RequestParts};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use std::ops::{Deref, DerefMut};
+
+mod de;
+
+use de::PathDeserializer;
+
+/// Extractor that will get captures from the URL and decode them.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::Path,
+///     routing::get,
+///     Router,
+/// };
+/// use uuid::Uuid;
+///
+/// async fn users_teams_show(
+///     Path((user_id, team_id)): Path<(String, String)>,
+/// ) {
+///     // ...
+/// }
+///
+/// let app = Router::new().route("/users/:user_id/team/:team_id", get(users_teams_show));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+///
+/// If the path contains only one parameter, then you can omit the tuple.
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::Path,
+///     routing::get,
+///     Router,
+/// };
+///
+/// async fn user_info(Path(user_id): Path<String>) {
+///     // ...
+/// }
+///
+/// let app = Router::new().route("/users/:user_id", get(user_info));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+///
+/// Path segments also can be deserialized into any type that implements
+/// [`serde::Deserialize`]. This includes `HashMap`s or custom structs.
+#[derive(Debug)]
+pub struct Path<T>(pub T);
+
+impl<T> Deref for Path<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Path<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// The URL parameters captured by the router for the route a request matched, stored as a
+/// request extension by the routing machinery.
+#[derive(Clone, Debug)]
+pub(crate) struct UrlParams(pub(crate) Vec<(String, String)>);
+
+#[async_trait]
+impl<T, B> FromRequest<B> for Path<T>
+where
+    T: DeserializeOwned + Send,
+    B: Send,
+{
+    type Rejection = FailedToDeserializePathParams;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let params = req
+            .extensions()
+            .and_then(|ext| ext.get::<UrlParams>())
+            .map(|UrlParams(params)| params.as_slice())
+            .unwrap_or_default();
+
+        T::deserialize(PathDeserializer::new(params))
+            .map(Path)
+            .map_err(FailedToDeserializePathParams::from_err)
+    }
+}
@@ -0,0 +1,252 @@
+//! Rejection response types.
+
+use crate::{body::BoxBody, response::IntoResponse, BoxError};
+use http::{Response, StatusCode};
+use std::fmt;
+
+macro_rules! define_rejection {
+    (
+        #[status = $status:ident]
+        #[body = $body:expr]
+        $(#[$m:meta])*
+        pub struct $name:ident;
+    ) => {
+        $(#[$m])*
+        #[derive(Debug)]
+        #[non_exhaustive]
+        pub struct $name;
+
+        impl IntoResponse for $name {
+            fn into_response(self) -> Response<BoxBody> {
+                let mut res = Response::new(crate::body::box_body(http_body::Full::from($body)));
+                *res.status_mut() = StatusCode::$status;
+                res
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str($body)
+            }
+        }
+
+        impl std::error::Error for $name {}
+    };
+
+    (
+        #[status = $status:ident]
+        #[body = $body:expr]
+        $(#[$m:meta])*
+        pub struct $name:ident(Error);
+    ) => {
+        $(#[$m])*
+        #[derive(Debug)]
+        pub struct $name(pub(crate) crate::Error);
+
+        impl $name {
+            pub(crate) fn from_err<E>(err: E) -> Self
+            where
+                E: Into<BoxError>,
+            {
+                Self(crate::Error::new(err))
+            }
+        }
+
+        impl IntoResponse for $name {
+            fn into_response(self) -> Response<BoxBody> {
+                let mut res = Response::new(crate::body::box_body(http_body::Full::from(
+                    format!(concat!($body, ": {}"), self.0),
+                )));
+                *res.status_mut() = StatusCode::$status;
+                res
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, concat!($body, ": {}"), self.0)
+            }
+        }
+
+        impl std::error::Error for $name {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+    };
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Request body didn't have the expected `Content-Type`"]
+    /// Rejection used if the `Content-Type` header didn't have the expected value.
+    pub struct MissingJsonContentType;
+}
+
+define_rejection! {
+    #[status = INTERNAL_SERVER_ERROR]
+    #[body = "Cannot have two extractors that consume the request body"]
+    /// Rejection used when two extractors that both consume the request body are used.
+    pub struct BodyAlreadyExtracted;
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Failed to parse the request body as JSON"]
+    /// Rejection used if the request body couldn't be parsed as valid JSON.
+    pub struct InvalidJsonBody(Error);
+}
+
+/// Rejection type for [`Json`](super::super::Json) used if the request body
+/// didn't contain valid JSON or wasn't labelled as JSON.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum JsonRejection {
+    /// The request body didn't have a `Content-Type: application/json` (or similar) header.
+    #[allow(missing_docs)]
+    MissingJsonContentType(MissingJsonContentType),
+    /// The request body couldn't be parsed as valid JSON.
+    #[allow(missing_docs)]
+    InvalidJsonBody(InvalidJsonBody),
+    /// Another extractor had already consumed the request body.
+    #[allow(missing_docs)]
+    BodyAlreadyExtracted(BodyAlreadyExtracted),
+}
+
+impl IntoResponse for JsonRejection {
+    fn into_response(self) -> Response<BoxBody> {
+        match self {
+            Self::MissingJsonContentType(inner) => inner.into_response(),
+            Self::InvalidJsonBody(inner) => inner.into_response(),
+            Self::BodyAlreadyExtracted(inner) => inner.into_response(),
+        }
+    }
+}
+
+impl fmt::Display for JsonRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingJsonContentType(inner) => inner.fmt(f),
+            Self::InvalidJsonBody(inner) => inner.fmt(f),
+            Self::BodyAlreadyExtracted(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for JsonRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::MissingJsonContentType(inner) => Some(inner),
+            Self::InvalidJsonBody(inner) => Some(inner),
+            Self::BodyAlreadyExtracted(inner) => Some(inner),
+        }
+    }
+}
+
+define_rejection! {
+    #[status = INTERNAL_SERVER_ERROR]
+    #[body = "Extension of the given type was not found"]
+    /// Rejection used if an `Extension` extractor's value is missing.
+    pub struct ExtensionRejection;
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Failed to deserialize path params"]
+    /// Rejection used if a `Path` extractor couldn't deserialize the captured params.
+    pub struct FailedToDeserializePathParams(Error);
+}
+
+define_rejection! {
+    #[status = BAD_REQUEST]
+    #[body = "Failed to deserialize query string"]
+    /// Rejection used if a `Query` extractor couldn't deserialize the query string.
+    pub struct FailedToDeserializeQueryString(Error);
+}
+
+define_rejection! {
+    #[status = PAYLOAD_TOO_LARGE]
+    #[body = "Request payload is too large"]
+    /// Rejection used if a `ContentLengthLimit` extractor's body was too large.
+    pub struct PayloadTooLarge;
+}
+
+define_rejection! {
+    #[status = LENGTH_REQUIRED]
+    #[body = "Content length header is required"]
+    /// Rejection used if a `ContentLengthLimit` extractor's body was missing a
+    /// `Content-Length` header.
+    pub struct LengthRequired;
+}
+
+/// Rejection used by [`Bearer`](super::auth::Bearer) if the `Authorization` header is missing or
+/// isn't a valid bearer credential.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BearerRejection {
+    /// The `Authorization` header was missing.
+    Missing,
+    /// The `Authorization` header wasn't a valid `Bearer <token>` value.
+    Invalid,
+}
+
+impl IntoResponse for BearerRejection {
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = Response::new(crate::body::box_body(http_body::Full::from(
+            self.to_string(),
+        )));
+        *res.status_mut() = StatusCode::UNAUTHORIZED;
+        res.headers_mut().insert(
+            http::header::WWW_AUTHENTICATE,
+            http::HeaderValue::from_static("Bearer"),
+        );
+        res
+    }
+}
+
+impl fmt::Display for BearerRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => f.write_str("Missing `Authorization` header"),
+            Self::Invalid => f.write_str("Invalid bearer token"),
+        }
+    }
+}
+
+impl std::error::Error for BearerRejection {}
+
+/// Rejection used by [`Basic`](super::auth::Basic) if the `Authorization` header is missing or
+/// isn't valid basic credentials.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BasicRejection {
+    /// The `Authorization` header was missing.
+    Missing,
+    /// The `Authorization` header wasn't a valid `Basic <base64>` value.
+    Invalid,
+}
+
+impl IntoResponse for BasicRejection {
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = Response::new(crate::body::box_body(http_body::Full::from(
+            self.to_string(),
+        )));
+        *res.status_mut() = StatusCode::UNAUTHORIZED;
+        res.headers_mut().insert(
+            http::header::WWW_AUTHENTICATE,
+            http::HeaderValue::from_static("Basic"),
+        );
+        res
+    }
+}
+
+impl fmt::Display for BasicRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => f.write_str("Missing `Authorization` header"),
+            Self::Invalid => f.write_str("Invalid basic credentials"),
+        }
+    }
+}
+
+impl std::error::Error for BasicRejection {}
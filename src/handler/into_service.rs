@@ -0,0 +1,67 @@
+use super::Handler;
+use crate::body::BoxBody;
+use http::Request;
+use std::{
+    convert::Infallible,
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower_service::Service;
+
+/// An adapter that makes a [`Handler`] into a [`Service`].
+///
+/// Created with [`Handler::into_service`].
+pub struct IntoService<H, B, T> {
+    handler: H,
+    _marker: PhantomData<fn() -> (B, T)>,
+}
+
+impl<H, B, T> IntoService<H, B, T> {
+    pub(super) fn new(handler: H) -> Self {
+        Self {
+            handler,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<H, B, T> fmt::Debug for IntoService<H, B, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoService").finish()
+    }
+}
+
+impl<H, B, T> Clone for IntoService<H, B, T>
+where
+    H: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<H, B, T> Service<Request<B>> for IntoService<H, B, T>
+where
+    H: Handler<B, T> + Clone + Send + 'static,
+    B: Send + 'static,
+    T: 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let handler = self.handler.clone();
+        Box::pin(async move { Ok(Handler::call(handler, req).await) })
+    }
+}
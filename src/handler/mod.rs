@@ -0,0 +1,163 @@
+//! Async functions that can be used to handle requests.
+//!
+//! See [`crate`] docs for more details.
+
+use crate::{
+    body::BoxBody,
+    extract::{FromRequest, RequestParts},
+    response::IntoResponse,
+    routing::IntoMakeService,
+};
+use async_trait::async_trait;
+use http::Request;
+use std::{convert::Infallible, fmt, future::Future, marker::PhantomData};
+use tower_layer::Layer;
+use tower_service::Service;
+
+mod into_service;
+
+pub use into_service::IntoService;
+
+/// Trait for async functions that can be used to handle requests.
+///
+/// You shouldn't need to depend on this trait directly. It is automatically implemented to
+/// closures that:
+///
+/// - Are `async fn`s.
+/// - Take no more than 16 arguments that all implement [`FromRequest`].
+/// - Returns something that implements [`IntoResponse`].
+/// - If a closure is used it must implement `Clone + Send` and be `'static`.
+/// - Returns a future that is `Send`.
+#[async_trait]
+pub trait Handler<B, T>: Clone + Send + Sized + 'static {
+    /// Call the handler with the given request.
+    async fn call(self, req: Request<B>) -> http::Response<BoxBody>;
+
+    /// Convert the handler into a [`Service`].
+    fn into_service(self) -> IntoService<Self, B, T> {
+        IntoService::new(self)
+    }
+
+    /// Apply a [`tower::Layer`] to the handler.
+    ///
+    /// All requests to the handler will be processed by the layer's corresponding middleware.
+    fn layer<L>(self, layer: L) -> Layered<L::Service, T>
+    where
+        L: Layer<IntoService<Self, B, T>>,
+    {
+        Layered::new(layer.layer(self.into_service()))
+    }
+}
+
+#[async_trait]
+impl<F, Fut, Res, B> Handler<B, ()> for F
+where
+    F: FnOnce() -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Res> + Send,
+    Res: IntoResponse,
+    B: Send + 'static,
+{
+    async fn call(self, _req: Request<B>) -> http::Response<BoxBody> {
+        self().await.into_response()
+    }
+}
+
+macro_rules! impl_handler {
+    ( $($ty:ident),* $(,)? ) => {
+        #[async_trait]
+        #[allow(non_snake_case, unused_mut)]
+        impl<F, Fut, B, Res, $($ty,)*> Handler<B, ($($ty,)*)> for F
+        where
+            F: FnOnce($($ty,)*) -> Fut + Clone + Send + 'static,
+            Fut: Future<Output = Res> + Send,
+            B: Send + 'static,
+            Res: IntoResponse,
+            $( $ty: FromRequest<B> + Send, )*
+        {
+            async fn call(self, req: Request<B>) -> http::Response<BoxBody> {
+                let mut req = RequestParts::new(req);
+
+                $(
+                    let $ty = match $ty::from_request(&mut req).await {
+                        Ok(value) => value,
+                        Err(rejection) => return rejection.into_response(),
+                    };
+                )*
+
+                self($($ty,)*).await.into_response()
+            }
+        }
+    };
+}
+
+all_the_tuples!(impl_handler);
+
+/// A [`Handler`] wrapped in a [`tower::Layer`].
+///
+/// Created with [`Handler::layer`].
+pub struct Layered<S, T> {
+    svc: S,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<S, T> fmt::Debug for Layered<S, T>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Layered").field("svc", &self.svc).finish()
+    }
+}
+
+impl<S, T> Clone for Layered<S, T>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            svc: self.svc.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<S, T, ReqBody> Handler<ReqBody, T> for Layered<S, T>
+where
+    S: Service<Request<ReqBody>, Response = http::Response<BoxBody>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+    T: 'static,
+    ReqBody: Send + 'static,
+{
+    async fn call(self, req: Request<ReqBody>) -> http::Response<BoxBody> {
+        use tower::ServiceExt;
+
+        match self.svc.oneshot(req).await {
+            Ok(res) => res,
+            Err(err) => match err {},
+        }
+    }
+}
+
+impl<S, T> Layered<S, T> {
+    pub(crate) fn new(svc: S) -> Self {
+        Self {
+            svc,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Extension trait for turning any [`Handler`] into a [`MakeService`](tower::make::MakeService).
+pub trait HandlerWithoutStateExt<B, T>: Handler<B, T> {
+    /// Convert the handler into a [`MakeService`](tower::make::MakeService) directly, without a
+    /// [`Router`](crate::Router) in between.
+    fn into_make_service(self) -> IntoMakeService<IntoService<Self, B, T>> {
+        IntoMakeService::new(self.into_service())
+    }
+}
+
+impl<H, B, T> HandlerWithoutStateExt<B, T> for H where H: Handler<B, T> {}
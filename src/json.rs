@@ -0,0 +1,152 @@
+use crate::{
+    body::{box_body, BoxBody},
+    extract::{rejection::*, FromRequest, RequestParts},
+    response::IntoResponse,
+    BoxError,
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{
+    header::{self, HeaderValue},
+    Response, StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// JSON Extractor / Response.
+///
+/// When used as an extractor, it can deserialize request bodies into some type that
+/// implements [`serde::Deserialize`]. The request will be rejected (and a [`JsonRejection`] will
+/// be returned) if:
+///
+/// - The request doesn't have a `Content-Type: application/json` (or similar) header.
+/// - The body doesn't contain syntactically valid JSON.
+/// - The body contains syntactically valid JSON but it couldn't be deserialized into the target
+///   type.
+/// - Buffering the request body fails.
+///
+/// When used as a response, it will serialize the value into JSON and respond with a
+/// `Content-Type: application/json` header.
+///
+/// # Extractor example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract,
+///     routing::post,
+///     Router,
+/// };
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct CreateUser {
+///     email: String,
+/// }
+///
+/// async fn create_user(extract::Json(payload): extract::Json<CreateUser>) {
+///     // ...
+/// }
+///
+/// let app = Router::new().route("/users", post(create_user));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[must_use]
+pub struct Json<T>(pub T);
+
+#[async_trait]
+impl<T, B> FromRequest<B> for Json<T>
+where
+    T: DeserializeOwned,
+    B: http_body::Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = JsonRejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        if json_content_type(req) {
+            let body = req.take_body().ok_or(BodyAlreadyExtracted)?;
+            let bytes = hyper::body::to_bytes(body)
+                .await
+                .map_err(InvalidJsonBody::from_err)?;
+            let value = serde_json::from_slice(&bytes).map_err(InvalidJsonBody::from_err)?;
+            Ok(Json(value))
+        } else {
+            Err(JsonRejection::MissingJsonContentType(MissingJsonContentType))
+        }
+    }
+}
+
+fn json_content_type<B>(req: &RequestParts<B>) -> bool {
+    let content_type = if let Some(content_type) = req
+        .headers()
+        .and_then(|headers| headers.get(header::CONTENT_TYPE))
+    {
+        content_type
+    } else {
+        return false;
+    };
+
+    let content_type = if let Ok(content_type) = content_type.to_str() {
+        content_type
+    } else {
+        return false;
+    };
+
+    let mime = if let Ok(mime) = content_type.parse::<mime::Mime>() {
+        mime
+    } else {
+        return false;
+    };
+
+    mime.type_() == "application"
+        && (mime.subtype() == "json" || mime.suffix().map_or(false, |name| name == "json"))
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(inner: T) -> Self {
+        Self(inner)
+    }
+}
+
+impl<T> IntoResponse for Json<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        match serde_json::to_vec(&self.0) {
+            Ok(bytes) => {
+                let mut res = Response::new(box_body(http_body::Full::from(Bytes::from(bytes))));
+                res.headers_mut().insert(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static("application/json"),
+                );
+                res
+            }
+            Err(err) => Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+                .body(box_body(http_body::Full::from(Bytes::from(
+                    err.to_string(),
+                ))))
+                .unwrap(),
+        }
+    }
+}
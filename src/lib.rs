@@ -1159,12 +1159,20 @@
 //!
 //! The following optional features are available:
 //!
+//! - `auth`: Enables the [`extract::Bearer`] and [`extract::Basic`] extractors and the
+//!   [`extract::RequireAuthorizationLayer`] middleware.
+//! - `cbor`: Registers `application/cbor` as a format [`response::Negotiate`] can serialize to.
 //! - `headers`: Enables extracting typed headers via [`extract::TypedHeader`].
 //! - `http1`: Enables hyper's `http1` feature. Enabled by default.
 //! - `http2`: Enables hyper's `http2` feature.
 //! - `json`: Enables the [`Json`] type and some similar convenience functionality.
 //!   Enabled by default.
+//! - `msgpack`: Registers `application/msgpack` as a format [`response::Negotiate`] can
+//!   serialize to.
 //! - `multipart`: Enables parsing `multipart/form-data` requests with [`extract::Multipart`].
+//! - `sensitive-headers`: Enables [`sensitive_headers::SetSensitiveHeadersLayer`], re-exported
+//!   from `tower-http`, for marking header names sensitive at the `Router` level.
+//! - `sse`: Enables Server-Sent Events responses via [`response::sse`].
 //! - `tower-log`: Enables `tower`'s `log` feature. Enabled by default.
 //! - `ws`: Enables WebSockets support via [`extract::ws`].
 //!
@@ -1251,6 +1259,8 @@ pub mod extract;
 pub mod handler;
 pub mod response;
 pub mod routing;
+#[cfg(feature = "sensitive-headers")]
+pub mod sensitive_headers;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,33 @@
+use super::IntoResponse;
+use crate::body::{box_body, BoxBody};
+use http::{
+    header::{self, HeaderValue},
+    Response,
+};
+
+/// An HTML response.
+///
+/// Will automatically get `Content-Type: text/html`.
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct Html<T>(pub T);
+
+impl<T> IntoResponse for Html<T>
+where
+    T: Into<hyper::Body>,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = Response::new(box_body(self.0.into()));
+        res.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/html; charset=utf-8"),
+        );
+        res
+    }
+}
+
+impl<T> From<T> for Html<T> {
+    fn from(inner: T) -> Self {
+        Self(inner)
+    }
+}
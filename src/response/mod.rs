@@ -0,0 +1,234 @@
+//! Types and traits for generating responses.
+//!
+//! See [`crate`] docs for more details.
+
+use crate::body::{box_body, BoxBody};
+use bytes::Bytes;
+use http::{
+    header::{self, Entry, HeaderMap, HeaderName, HeaderValue},
+    Response, StatusCode,
+};
+use std::convert::Infallible;
+
+mod html;
+#[cfg(feature = "json")]
+mod negotiate;
+mod set_header;
+#[cfg(feature = "sse")]
+pub mod sse;
+
+pub use html::Html;
+#[cfg(feature = "json")]
+pub use negotiate::Negotiate;
+pub use set_header::{SetResponseHeader, SetResponseHeaderLayer};
+#[cfg(feature = "sse")]
+pub use sse::Sse;
+
+/// Trait for generating responses.
+///
+/// Types that implement `IntoResponse` can be returned from handlers.
+pub trait IntoResponse {
+    /// Create a response.
+    fn into_response(self) -> Response<BoxBody>;
+}
+
+impl IntoResponse for () {
+    fn into_response(self) -> Response<BoxBody> {
+        Response::new(crate::body::empty())
+    }
+}
+
+impl IntoResponse for Infallible {
+    fn into_response(self) -> Response<BoxBody> {
+        match self {}
+    }
+}
+
+impl<T, E> IntoResponse for Result<T, E>
+where
+    T: IntoResponse,
+    E: IntoResponse,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        match self {
+            Ok(value) => value.into_response(),
+            Err(err) => err.into_response(),
+        }
+    }
+}
+
+impl IntoResponse for StatusCode {
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = Response::new(crate::body::empty());
+        *res.status_mut() = self;
+        res
+    }
+}
+
+impl IntoResponse for Response<BoxBody> {
+    fn into_response(self) -> Response<BoxBody> {
+        self
+    }
+}
+
+impl IntoResponse for &'static str {
+    fn into_response(self) -> Response<BoxBody> {
+        Bytes::from_static(self.as_bytes()).into_response()
+    }
+}
+
+impl IntoResponse for String {
+    fn into_response(self) -> Response<BoxBody> {
+        Bytes::from(self).into_response()
+    }
+}
+
+impl IntoResponse for Bytes {
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = Response::new(box_body(http_body::Full::from(self)));
+        res.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; charset=utf-8"),
+        );
+        res
+    }
+}
+
+impl IntoResponse for Vec<u8> {
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = Response::new(box_body(http_body::Full::from(Bytes::from(self))));
+        res.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/octet-stream"),
+        );
+        res
+    }
+}
+
+impl<R> IntoResponse for (StatusCode, R)
+where
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = self.1.into_response();
+        *res.status_mut() = self.0;
+        res
+    }
+}
+
+impl<R> IntoResponse for (HeaderMap, R)
+where
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = self.1.into_response();
+        res.headers_mut().extend(self.0);
+        res
+    }
+}
+
+impl<R> IntoResponse for (StatusCode, HeaderMap, R)
+where
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = self.2.into_response();
+        *res.status_mut() = self.0;
+        res.headers_mut().extend(self.1);
+        res
+    }
+}
+
+/// An HTTP response with headers built more easily than [`HeaderMap`] allows.
+///
+/// # Example
+///
+/// ```rust
+/// use axum::response::{IntoResponse, Headers};
+///
+/// async fn handler() -> impl IntoResponse {
+///     Headers(vec![("x-foo", "foo")])
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct Headers<H>(pub H);
+
+impl<H, K, V> IntoResponse for Headers<H>
+where
+    H: IntoIterator<Item = (K, V)>,
+    K: TryInto<HeaderName>,
+    V: TryInto<HeaderValue>,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = ().into_response();
+
+        // `append`, not `insert`, so repeating a header name (e.g. several `Set-Cookie`s) adds
+        // another value instead of overwriting the previous one.
+        for (key, value) in self.0 {
+            if let (Ok(key), Ok(value)) = (key.try_into(), value.try_into()) {
+                res.headers_mut().append(key, value);
+            }
+        }
+
+        res
+    }
+}
+
+impl<H> Headers<H> {
+    /// Mark the given header names as sensitive on this response, so they're omitted by things
+    /// like `tower_http`'s `TraceLayer` (see [`crate::sensitive_headers`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use axum::response::{Headers, IntoResponse};
+    /// use http::header::AUTHORIZATION;
+    ///
+    /// async fn handler() -> impl IntoResponse {
+    ///     Headers(vec![(AUTHORIZATION, "Bearer secret")]).sensitive([AUTHORIZATION])
+    /// }
+    /// ```
+    pub fn sensitive<I>(self, names: I) -> SensitiveHeaders<H>
+    where
+        I: IntoIterator<Item = HeaderName>,
+    {
+        SensitiveHeaders {
+            headers: self,
+            sensitive: names.into_iter().collect(),
+        }
+    }
+}
+
+/// A [`Headers`] response with some of its header names marked sensitive.
+///
+/// Created with [`Headers::sensitive`].
+#[derive(Debug)]
+#[must_use]
+pub struct SensitiveHeaders<H> {
+    headers: Headers<H>,
+    sensitive: Vec<HeaderName>,
+}
+
+impl<H, K, V> IntoResponse for SensitiveHeaders<H>
+where
+    H: IntoIterator<Item = (K, V)>,
+    K: TryInto<HeaderName>,
+    V: TryInto<HeaderValue>,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        let mut res = self.headers.into_response();
+
+        for name in &self.sensitive {
+            // `get_mut` only reaches the first value for a given header name; a response can
+            // carry several (e.g. multiple `Set-Cookie`s), so mark every one of them.
+            if let Entry::Occupied(mut entry) = res.headers_mut().entry(name) {
+                for value in entry.iter_mut() {
+                    value.set_sensitive(true);
+                }
+            }
+        }
+
+        res
+    }
+}
@@ -0,0 +1,170 @@
+use super::IntoResponse;
+use crate::{
+    body::{box_body, BoxBody},
+    extract::AcceptedTypes,
+    BoxError,
+};
+use bytes::Bytes;
+use http::{
+    header::{self, HeaderValue},
+    Response, StatusCode,
+};
+use serde::Serialize;
+
+/// A response that picks the best representation of `value` for the client, based on the
+/// [`AcceptedTypes`] captured from its `Accept` header.
+///
+/// JSON (`application/json`) is always available. Enabling the `cbor` or `msgpack` feature
+/// registers `application/cbor` or `application/msgpack` as additional candidates. If none of the
+/// registered formats are acceptable to the client, the response is `406 Not Acceptable`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     extract::AcceptedTypes,
+///     response::Negotiate,
+///     routing::get,
+///     Router,
+/// };
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// async fn get_user(accepted: AcceptedTypes) -> Negotiate<User> {
+///     Negotiate::new(User { name: "Alice".to_owned() }, accepted)
+/// }
+///
+/// let app = Router::new().route("/user", get(get_user));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Negotiate<T> {
+    value: T,
+    accepted: AcceptedTypes,
+}
+
+impl<T> Negotiate<T> {
+    /// Create a `Negotiate` that will respond with `value`, encoded in whichever format
+    /// `accepted` allows.
+    pub fn new(value: T, accepted: AcceptedTypes) -> Self {
+        Self { value, accepted }
+    }
+}
+
+/// One of the wire formats [`Negotiate`] knows how to serialize a value into.
+///
+/// JSON is always registered. `Cbor` and `MessagePack` only exist when their like-named feature
+/// is enabled, mirroring how the `json` feature gates [`Json`](crate::Json).
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    Json,
+    #[cfg(feature = "cbor")]
+    Cbor,
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+}
+
+impl Format {
+    fn registered() -> Vec<Self> {
+        #[cfg_attr(not(any(feature = "cbor", feature = "msgpack")), allow(unused_mut))]
+        let mut formats = vec![Self::Json];
+        #[cfg(feature = "cbor")]
+        formats.push(Self::Cbor);
+        #[cfg(feature = "msgpack")]
+        formats.push(Self::MessagePack);
+        formats
+    }
+
+    fn mime(self) -> mime::Mime {
+        match self {
+            Self::Json => mime::APPLICATION_JSON,
+            #[cfg(feature = "cbor")]
+            Self::Cbor => "application/cbor"
+                .parse()
+                .expect("application/cbor is a valid mime type"),
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => "application/msgpack"
+                .parse()
+                .expect("application/msgpack is a valid mime type"),
+        }
+    }
+
+    fn content_type(self) -> HeaderValue {
+        match self {
+            Self::Json => HeaderValue::from_static("application/json"),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => HeaderValue::from_static("application/cbor"),
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => HeaderValue::from_static("application/msgpack"),
+        }
+    }
+
+    fn encode<T>(self, value: &T) -> Result<Bytes, BoxError>
+    where
+        T: Serialize,
+    {
+        match self {
+            Self::Json => Ok(Bytes::from(serde_json::to_vec(value)?)),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => {
+                let mut buf = Vec::new();
+                serde_cbor::to_writer(&mut buf, value)?;
+                Ok(Bytes::from(buf))
+            }
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => Ok(Bytes::from(rmp_serde::to_vec(value)?)),
+        }
+    }
+}
+
+impl<T> IntoResponse for Negotiate<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        let formats = Format::registered();
+        let mimes = formats
+            .iter()
+            .map(|format| format.mime())
+            .collect::<Vec<_>>();
+        let offered = mimes.iter().collect::<Vec<_>>();
+
+        let chosen = match self.accepted.best_match(&offered) {
+            Some(chosen) => chosen,
+            None => {
+                let mut res = Response::new(crate::body::empty());
+                *res.status_mut() = StatusCode::NOT_ACCEPTABLE;
+                return res;
+            }
+        };
+
+        let idx = mimes
+            .iter()
+            .position(|mime| mime == chosen)
+            .expect("`chosen` was returned from `offered`, which was built from `mimes`");
+        let format = formats[idx];
+
+        match format.encode(&self.value) {
+            Ok(bytes) => {
+                let mut res = Response::new(box_body(http_body::Full::from(bytes)));
+                res.headers_mut()
+                    .insert(header::CONTENT_TYPE, format.content_type());
+                res
+            }
+            Err(err) => Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+                .body(box_body(http_body::Full::from(Bytes::from(
+                    err.to_string(),
+                ))))
+                .unwrap(),
+        }
+    }
+}
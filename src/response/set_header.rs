@@ -0,0 +1,197 @@
+use crate::body::BoxBody;
+use futures_util::future::BoxFuture;
+use http::{HeaderName, HeaderValue, Request, Response};
+use std::{
+    fmt,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+#[derive(Debug, Clone, Copy)]
+enum InsertHeaderMode {
+    Override,
+    Append,
+    IfNotPresent,
+}
+
+/// [`Layer`] that applies [`SetResponseHeader`], adding a response header computed from the
+/// outgoing [`Response`] to every route in a [`Router`](crate::Router).
+///
+/// Unlike a per-handler [`Headers`](super::Headers) return value, this runs for every route the
+/// layer is applied to and sees the response the inner service actually produced, so it can
+/// derive values like `Content-Length`, an `ETag`, or a correlation ID from the generated
+/// body/status.
+///
+/// # Example
+///
+/// ```rust
+/// use axum::{response::SetResponseHeaderLayer, routing::get, Router};
+/// use http::header::ETAG;
+///
+/// let app = Router::new().route("/", get(|| async { "hi!" })).layer(
+///     SetResponseHeaderLayer::if_not_present(ETAG, |res: &http::Response<_>| {
+///         let _ = res;
+///         None
+///     }),
+/// );
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+pub struct SetResponseHeaderLayer<F> {
+    header_name: HeaderName,
+    make_value: Arc<F>,
+    mode: InsertHeaderMode,
+}
+
+impl<F> SetResponseHeaderLayer<F>
+where
+    F: Fn(&Response<BoxBody>) -> Option<HeaderValue>,
+{
+    /// Create a layer that overrides any existing value for `header_name` with the value
+    /// `make_value` computes, if any.
+    pub fn overriding(header_name: HeaderName, make_value: F) -> Self {
+        Self::new(header_name, make_value, InsertHeaderMode::Override)
+    }
+
+    /// Create a layer that appends the value `make_value` computes to `header_name`, keeping any
+    /// value already present.
+    pub fn appending(header_name: HeaderName, make_value: F) -> Self {
+        Self::new(header_name, make_value, InsertHeaderMode::Append)
+    }
+
+    /// Create a layer that only sets `header_name` if it isn't already present on the response.
+    pub fn if_not_present(header_name: HeaderName, make_value: F) -> Self {
+        Self::new(header_name, make_value, InsertHeaderMode::IfNotPresent)
+    }
+
+    fn new(header_name: HeaderName, make_value: F, mode: InsertHeaderMode) -> Self {
+        Self {
+            header_name,
+            make_value: Arc::new(make_value),
+            mode,
+        }
+    }
+}
+
+impl<F> Clone for SetResponseHeaderLayer<F> {
+    fn clone(&self) -> Self {
+        Self {
+            header_name: self.header_name.clone(),
+            make_value: Arc::clone(&self.make_value),
+            mode: self.mode,
+        }
+    }
+}
+
+impl<F> fmt::Debug for SetResponseHeaderLayer<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetResponseHeaderLayer")
+            .field("header_name", &self.header_name)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl<S, F> Layer<S> for SetResponseHeaderLayer<F>
+where
+    F: Fn(&Response<BoxBody>) -> Option<HeaderValue>,
+{
+    type Service = SetResponseHeader<S, F>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        SetResponseHeader {
+            inner,
+            header_name: self.header_name.clone(),
+            make_value: Arc::clone(&self.make_value),
+            mode: self.mode,
+        }
+    }
+}
+
+/// [`Service`] that adds a response header computed from the outgoing [`Response`].
+///
+/// See [`SetResponseHeaderLayer`] for more details.
+pub struct SetResponseHeader<S, F> {
+    inner: S,
+    header_name: HeaderName,
+    make_value: Arc<F>,
+    mode: InsertHeaderMode,
+}
+
+impl<S, F> Clone for SetResponseHeader<S, F>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            header_name: self.header_name.clone(),
+            make_value: Arc::clone(&self.make_value),
+            mode: self.mode,
+        }
+    }
+}
+
+impl<S, F> fmt::Debug for SetResponseHeader<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetResponseHeader")
+            .field("inner", &self.inner)
+            .field("header_name", &self.header_name)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl<S, F, ReqBody> Service<Request<ReqBody>> for SetResponseHeader<S, F>
+where
+    S: Service<Request<ReqBody>, Response = Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    F: Fn(&Response<BoxBody>) -> Option<HeaderValue> + Send + Sync + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let not_ready_inner = self.inner.clone();
+        let mut ready_inner = std::mem::replace(&mut self.inner, not_ready_inner);
+        let header_name = self.header_name.clone();
+        let make_value = Arc::clone(&self.make_value);
+        let mode = self.mode;
+
+        Box::pin(async move {
+            let mut res = ready_inner.call(req).await?;
+
+            let should_compute = match mode {
+                InsertHeaderMode::Override | InsertHeaderMode::Append => true,
+                InsertHeaderMode::IfNotPresent => !res.headers().contains_key(&header_name),
+            };
+
+            if should_compute {
+                if let Some(value) = make_value(&res) {
+                    match mode {
+                        InsertHeaderMode::Override | InsertHeaderMode::IfNotPresent => {
+                            res.headers_mut().insert(header_name.clone(), value);
+                        }
+                        InsertHeaderMode::Append => {
+                            res.headers_mut().append(header_name.clone(), value);
+                        }
+                    }
+                }
+            }
+
+            Ok(res)
+        })
+    }
+}
@@ -0,0 +1,314 @@
+//! Server-Sent Events (SSE) responses.
+
+use super::IntoResponse;
+use crate::{
+    body::{box_body, BoxBody},
+    BoxError,
+};
+use bytes::Bytes;
+use futures_core::Stream;
+use http::{
+    header::{self, HeaderValue},
+    Response,
+};
+use pin_project_lite::pin_project;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{Instant, Sleep};
+
+/// A response that streams [`Event`]s to the client as `text/event-stream`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{response::sse::{Event, Sse}, routing::get, Router};
+/// use std::convert::Infallible;
+/// use futures::stream::{self, Stream};
+///
+/// async fn sse_handler() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+///     let stream = stream::repeat_with(|| Ok(Event::default().data("hi!")));
+///     Sse::new(stream)
+/// }
+///
+/// let app = Router::new().route("/sse", get(sse_handler));
+/// # async {
+/// # axum::Server::bind(&"".parse().unwrap()).serve(app.into_make_service()).await.unwrap();
+/// # };
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Sse<S> {
+    stream: S,
+    keep_alive: Option<KeepAlive>,
+}
+
+impl<S> Sse<S> {
+    /// Create a new `Sse` response that streams `stream`'s events to the client.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            keep_alive: None,
+        }
+    }
+
+    /// Keep the connection alive by sending a comment line at a configurable interval while
+    /// `stream` is idle, so intermediate proxies don't time it out.
+    pub fn keep_alive(mut self, keep_alive: KeepAlive) -> Self {
+        self.keep_alive = Some(keep_alive);
+        self
+    }
+}
+
+impl<S, E> IntoResponse for Sse<S>
+where
+    S: Stream<Item = Result<Event, E>> + Send + 'static,
+    E: Into<BoxError> + 'static,
+{
+    fn into_response(self) -> Response<BoxBody> {
+        let body = SseBody {
+            stream: self.stream,
+            keep_alive: self.keep_alive.map(KeepAliveTimer::new),
+        };
+
+        let mut res = Response::new(box_body(body));
+        res.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/event-stream"),
+        );
+        res.headers_mut()
+            .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+        res
+    }
+}
+
+pin_project! {
+    struct SseBody<S> {
+        #[pin]
+        stream: S,
+        keep_alive: Option<KeepAliveTimer>,
+    }
+}
+
+impl<S, E> http_body::Body for SseBody<S>
+where
+    S: Stream<Item = Result<Event, E>>,
+    E: Into<BoxError>,
+{
+    type Data = Bytes;
+    type Error = BoxError;
+
+    fn poll_data(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let this = self.project();
+
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(Ok(event))) => {
+                if let Some(keep_alive) = this.keep_alive {
+                    keep_alive.reset();
+                }
+                Poll::Ready(Some(Ok(event.into_bytes())))
+            }
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err.into()))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => match this.keep_alive {
+                Some(keep_alive) => keep_alive.poll_event(cx).map(Ok).map(Some),
+                None => Poll::Pending,
+            },
+        }
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(None))
+    }
+}
+
+/// Configures [`Sse`]'s keep-alive behavior.
+#[derive(Debug, Clone)]
+pub struct KeepAlive {
+    event: Bytes,
+    interval: Duration,
+}
+
+impl KeepAlive {
+    /// Create a `KeepAlive` that sends an empty comment line (`:\n\n`) every 15 seconds.
+    pub fn new() -> Self {
+        Self {
+            event: Bytes::from_static(b":\n\n"),
+            interval: Duration::from_secs(15),
+        }
+    }
+
+    /// Set how often a keep-alive event is sent while `stream` is idle.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the text of the comment line sent as a keep-alive event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `text` contains a newline.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        let text = text.into();
+        assert!(
+            !text.contains('\n'),
+            "SSE keep-alive text cannot contain newlines"
+        );
+        self.event = Bytes::from(format!(":{}\n\n", text));
+        self
+    }
+}
+
+impl Default for KeepAlive {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct KeepAliveTimer {
+    event: Bytes,
+    interval: Duration,
+    timer: Pin<Box<Sleep>>,
+}
+
+impl KeepAliveTimer {
+    fn new(keep_alive: KeepAlive) -> Self {
+        Self {
+            timer: Box::pin(tokio::time::sleep(keep_alive.interval)),
+            event: keep_alive.event,
+            interval: keep_alive.interval,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.timer
+            .as_mut()
+            .reset(Instant::now() + self.interval);
+    }
+
+    fn poll_event(&mut self, cx: &mut Context<'_>) -> Poll<Bytes> {
+        match self.timer.as_mut().poll(cx) {
+            Poll::Ready(()) => {
+                self.reset();
+                Poll::Ready(self.event.clone())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// A single server-sent event, encoded per the SSE wire format when the response is sent.
+#[derive(Debug, Default, Clone)]
+#[must_use]
+pub struct Event {
+    id: Option<String>,
+    event: Option<String>,
+    data: Option<String>,
+    retry: Option<Duration>,
+    comment: Option<String>,
+}
+
+impl Event {
+    /// Set the event's `data:` field. Multi-line values are split across several `data:` lines,
+    /// per the SSE wire format.
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Set the event's `event:` field, naming the type of event for the client's listeners.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `event` contains a newline.
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        let event = event.into();
+        assert!(!event.contains('\n'), "SSE event name cannot contain newlines");
+        self.event = Some(event);
+        self
+    }
+
+    /// Set the event's `id:` field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` contains a newline.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        let id = id.into();
+        assert!(!id.contains('\n'), "SSE id cannot contain newlines");
+        self.id = Some(id);
+        self
+    }
+
+    /// Set the event's `retry:` field, telling the client how long to wait before reconnecting.
+    pub fn retry(mut self, retry: Duration) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Attach a comment line (`:<comment>`) to the event, ignored by clients but useful for
+    /// debugging the raw stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `comment` contains a newline.
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        let comment = comment.into();
+        assert!(
+            !comment.contains('\n'),
+            "SSE comment cannot contain newlines"
+        );
+        self.comment = Some(comment);
+        self
+    }
+
+    fn into_bytes(self) -> Bytes {
+        let mut buf = String::new();
+
+        if let Some(comment) = &self.comment {
+            buf.push(':');
+            buf.push_str(comment);
+            buf.push('\n');
+        }
+
+        if let Some(event) = &self.event {
+            buf.push_str("event:");
+            buf.push_str(event);
+            buf.push('\n');
+        }
+
+        if let Some(data) = &self.data {
+            for line in data.split('\n') {
+                buf.push_str("data:");
+                buf.push_str(line);
+                buf.push('\n');
+            }
+        }
+
+        if let Some(id) = &self.id {
+            buf.push_str("id:");
+            buf.push_str(id);
+            buf.push('\n');
+        }
+
+        if let Some(retry) = self.retry {
+            buf.push_str("retry:");
+            buf.push_str(&retry.as_millis().to_string());
+            buf.push('\n');
+        }
+
+        buf.push('\n');
+
+        Bytes::from(buf)
+    }
+}
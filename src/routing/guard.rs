@@ -0,0 +1,150 @@
+//! Route guards — predicates that gate dispatch to an endpoint in addition to (or instead of)
+//! matching on the HTTP method.
+
+use crate::extract::RequestParts;
+use std::{fmt, sync::Arc};
+
+/// A predicate that decides whether a request should be routed to a particular endpoint.
+///
+/// Guards are checked after the [`MethodFilter`](super::MethodFilter) matches, which lets two
+/// handlers share the same method and path while being picked between based on something else
+/// about the request, such as a header, the `Host`, or the query string.
+///
+/// Built with one of the free functions in this module ([`header`], [`host`], [`fn_guard`]), the
+/// combinators [`All`], [`Any`], and [`Not`], or by implementing this trait directly. Attach a
+/// guard to an endpoint with [`MethodRouter::guard`](super::MethodRouter::guard).
+pub trait Guard<B = crate::body::Body>: Send + Sync {
+    /// Returns `true` if `req` should be dispatched to the endpoint this guard is attached to.
+    fn check(&self, req: &RequestParts<B>) -> bool;
+}
+
+impl<B> Guard<B> for Arc<dyn Guard<B>> {
+    fn check(&self, req: &RequestParts<B>) -> bool {
+        (**self).check(req)
+    }
+}
+
+/// Returns a [`Guard`] that requires the header `name` to be present with exactly `value`.
+pub fn header<B>(name: &'static str, value: &'static str) -> impl Guard<B> {
+    Header { name, value }
+}
+
+struct Header {
+    name: &'static str,
+    value: &'static str,
+}
+
+impl<B> Guard<B> for Header {
+    fn check(&self, req: &RequestParts<B>) -> bool {
+        req.headers()
+            .and_then(|headers| headers.get(self.name))
+            .and_then(|value| value.to_str().ok())
+            .map_or(false, |value| value == self.value)
+    }
+}
+
+/// Returns a [`Guard`] that requires the request's `Host` (from the `Host` header, falling back
+/// to the host part of the request URI, without any port) to match `host` exactly.
+pub fn host<B>(host: &'static str) -> impl Guard<B> {
+    Host(host)
+}
+
+struct Host(&'static str);
+
+impl<B> Guard<B> for Host {
+    fn check(&self, req: &RequestParts<B>) -> bool {
+        let from_header = req
+            .headers()
+            .and_then(|headers| headers.get(http::header::HOST))
+            .and_then(|value| value.to_str().ok());
+
+        let from_uri = req.uri().host();
+
+        from_header.or(from_uri).map_or(false, |h| h == self.0)
+    }
+}
+
+/// Returns a [`Guard`] backed by an arbitrary closure.
+pub fn fn_guard<B, F>(f: F) -> impl Guard<B>
+where
+    F: Fn(&RequestParts<B>) -> bool + Send + Sync,
+{
+    FnGuard(f)
+}
+
+struct FnGuard<F>(F);
+
+impl<B, F> Guard<B> for FnGuard<F>
+where
+    F: Fn(&RequestParts<B>) -> bool + Send + Sync,
+{
+    fn check(&self, req: &RequestParts<B>) -> bool {
+        (self.0)(req)
+    }
+}
+
+/// A [`Guard`] combinator requiring all of the given guards to pass.
+pub struct All<B>(Vec<Arc<dyn Guard<B>>>);
+
+impl<B> All<B> {
+    /// Create an [`All`] combinator from a list of guards.
+    pub fn new(guards: Vec<Arc<dyn Guard<B>>>) -> Self {
+        Self(guards)
+    }
+}
+
+impl<B> Guard<B> for All<B> {
+    fn check(&self, req: &RequestParts<B>) -> bool {
+        self.0.iter().all(|guard| guard.check(req))
+    }
+}
+
+impl<B> fmt::Debug for All<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("All").field(&self.0.len()).finish()
+    }
+}
+
+/// A [`Guard`] combinator requiring at least one of the given guards to pass.
+pub struct Any<B>(Vec<Arc<dyn Guard<B>>>);
+
+impl<B> Any<B> {
+    /// Create an [`Any`] combinator from a list of guards.
+    pub fn new(guards: Vec<Arc<dyn Guard<B>>>) -> Self {
+        Self(guards)
+    }
+}
+
+impl<B> Guard<B> for Any<B> {
+    fn check(&self, req: &RequestParts<B>) -> bool {
+        self.0.iter().any(|guard| guard.check(req))
+    }
+}
+
+impl<B> fmt::Debug for Any<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Any").field(&self.0.len()).finish()
+    }
+}
+
+/// A [`Guard`] combinator negating the result of another guard.
+pub struct Not<B>(Arc<dyn Guard<B>>);
+
+impl<B> Not<B> {
+    /// Create a [`Not`] combinator that inverts `guard`.
+    pub fn new(guard: impl Guard<B> + 'static) -> Self {
+        Self(Arc::new(guard))
+    }
+}
+
+impl<B> Guard<B> for Not<B> {
+    fn check(&self, req: &RequestParts<B>) -> bool {
+        !self.0.check(req)
+    }
+}
+
+impl<B> fmt::Debug for Not<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Not").finish()
+    }
+}
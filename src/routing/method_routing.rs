@@ -0,0 +1,405 @@
+use super::{
+    guard::Guard,
+    route::{from_service, BoxResponseBody, Route},
+};
+use crate::{body::BoxBody, extract::RequestParts, handler::Handler, BoxError};
+use http::{Method, Request, Response, StatusCode};
+use std::{convert::Infallible, fmt, future::Future, pin::Pin, sync::Arc, task::Context};
+use tower_service::Service;
+
+mod private {
+    //! Allows [`MethodRouter`](super::MethodRouter)'s chaining methods (`.get`, `.post`, `.on`,
+    //! ...) to accept either a [`Handler`](super::Handler) or a [`Service`](tower_service::Service)
+    //! without exposing the distinction to callers.
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct ViaHandler<T>(std::marker::PhantomData<fn() -> T>);
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct ViaService;
+
+    pub trait IntoEndpoint<B, E, T> {
+        fn into_endpoint(self) -> Route<B, E>;
+    }
+
+    impl<H, T, B> IntoEndpoint<B, Infallible, ViaHandler<T>> for H
+    where
+        H: Handler<B, T>,
+        T: 'static,
+        B: Send + 'static,
+    {
+        fn into_endpoint(self) -> Route<B, Infallible> {
+            from_service(self.into_service())
+        }
+    }
+
+    impl<S, B> IntoEndpoint<B, S::Error, ViaService> for S
+    where
+        S: Service<Request<B>, Response = Response<BoxBody>> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+    {
+        fn into_endpoint(self) -> Route<B, S::Error> {
+            from_service(self)
+        }
+    }
+}
+
+pub(crate) use private::IntoEndpoint;
+use private::{ViaHandler, ViaService};
+
+/// A filter that matches one or more HTTP methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MethodFilter(u16);
+
+impl MethodFilter {
+    /// Match `CONNECT` requests.
+    pub const CONNECT: Self = Self(0b0_0000_0001);
+    /// Match `DELETE` requests.
+    pub const DELETE: Self = Self(0b0_0000_0010);
+    /// Match `GET` requests.
+    pub const GET: Self = Self(0b0_0000_0100);
+    /// Match `HEAD` requests.
+    pub const HEAD: Self = Self(0b0_0000_1000);
+    /// Match `OPTIONS` requests.
+    pub const OPTIONS: Self = Self(0b0_0001_0000);
+    /// Match `PATCH` requests.
+    pub const PATCH: Self = Self(0b0_0010_0000);
+    /// Match `POST` requests.
+    pub const POST: Self = Self(0b0_0100_0000);
+    /// Match `PUT` requests.
+    pub const PUT: Self = Self(0b0_1000_0000);
+    /// Match `TRACE` requests.
+    pub const TRACE: Self = Self(0b1_0000_0000);
+
+    /// Returns whether this filter matches `other`.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    fn from_method(method: &Method) -> Option<Self> {
+        Some(match *method {
+            Method::CONNECT => Self::CONNECT,
+            Method::DELETE => Self::DELETE,
+            Method::GET => Self::GET,
+            Method::HEAD => Self::HEAD,
+            Method::OPTIONS => Self::OPTIONS,
+            Method::PATCH => Self::PATCH,
+            Method::POST => Self::POST,
+            Method::PUT => Self::PUT,
+            Method::TRACE => Self::TRACE,
+            _ => return None,
+        })
+    }
+}
+
+impl std::ops::BitOr for MethodFilter {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// An endpoint registered with a [`MethodRouter`]: which methods it answers to, an optional
+/// [`Guard`] that further restricts it, and the [`Route`] to dispatch to.
+struct Endpoint<B, E> {
+    filter: MethodFilter,
+    guard: Option<Arc<dyn Guard<B>>>,
+    route: Route<B, E>,
+}
+
+impl<B, E> Clone for Endpoint<B, E> {
+    fn clone(&self) -> Self {
+        Self {
+            filter: self.filter,
+            guard: self.guard.clone(),
+            route: self.route.clone(),
+        }
+    }
+}
+
+/// A [`Service`] that routes requests to one of several handlers based on the HTTP method (and,
+/// optionally, a [`Guard`]).
+///
+/// Created with [`get`], [`post`], [`on`], and friends, and extended by chaining further calls
+/// such as `.post(...)` or `.guard(...)`.
+pub struct MethodRouter<B = crate::body::Body, E = Infallible> {
+    routes: Vec<Endpoint<B, E>>,
+}
+
+impl<B, E> Clone for MethodRouter<B, E> {
+    fn clone(&self) -> Self {
+        Self {
+            routes: self.routes.clone(),
+        }
+    }
+}
+
+impl<B, E> fmt::Debug for MethodRouter<B, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MethodRouter").finish()
+    }
+}
+
+macro_rules! top_level_method_fn {
+    ($name:ident, $filter:ident) => {
+        #[doc = concat!("Route `", stringify!($filter), "` requests to `handler`.")]
+        pub fn $name<H, T, B>(handler: H) -> MethodRouter<B, Infallible>
+        where
+            H: Handler<B, T>,
+            T: 'static,
+            B: Send + 'static,
+        {
+            MethodRouter::new().$name(handler)
+        }
+    };
+}
+
+top_level_method_fn!(connect, CONNECT);
+top_level_method_fn!(delete, DELETE);
+top_level_method_fn!(get, GET);
+top_level_method_fn!(head, HEAD);
+top_level_method_fn!(options, OPTIONS);
+top_level_method_fn!(patch, PATCH);
+top_level_method_fn!(post, POST);
+top_level_method_fn!(put, PUT);
+top_level_method_fn!(trace, TRACE);
+
+/// Route requests matching `filter` to `handler`.
+pub fn on<H, T, B>(filter: MethodFilter, handler: H) -> MethodRouter<B, Infallible>
+where
+    H: Handler<B, T>,
+    T: 'static,
+    B: Send + 'static,
+{
+    MethodRouter::new().on(filter, handler)
+}
+
+/// Route requests with any method to `handler`.
+pub fn any<H, T, B>(handler: H) -> MethodRouter<B, Infallible>
+where
+    H: Handler<B, T>,
+    T: 'static,
+    B: Send + 'static,
+{
+    MethodRouter::new().on(
+        MethodFilter::CONNECT
+            | MethodFilter::DELETE
+            | MethodFilter::GET
+            | MethodFilter::HEAD
+            | MethodFilter::OPTIONS
+            | MethodFilter::PATCH
+            | MethodFilter::POST
+            | MethodFilter::PUT
+            | MethodFilter::TRACE,
+        handler,
+    )
+}
+
+macro_rules! chained_method_fn {
+    ($name:ident, $filter:ident) => {
+        #[doc = concat!("Chain a handler or service for `", stringify!($filter), "` requests onto this `MethodRouter`.")]
+        pub fn $name<H, T>(self, handler: H) -> Self
+        where
+            H: IntoEndpoint<B, E, T>,
+        {
+            self.on(MethodFilter::$filter, handler)
+        }
+    };
+}
+
+impl<B, E> MethodRouter<B, E>
+where
+    B: Send + 'static,
+{
+    /// Create a new, empty `MethodRouter`.
+    pub fn new() -> Self {
+        Self { routes: Vec::new() }
+    }
+
+    chained_method_fn!(connect, CONNECT);
+    chained_method_fn!(delete, DELETE);
+    chained_method_fn!(get, GET);
+    chained_method_fn!(head, HEAD);
+    chained_method_fn!(options, OPTIONS);
+    chained_method_fn!(patch, PATCH);
+    chained_method_fn!(post, POST);
+    chained_method_fn!(put, PUT);
+    chained_method_fn!(trace, TRACE);
+
+    /// Chain a handler or service for requests matching `filter` onto this `MethodRouter`.
+    pub fn on<H, T>(mut self, filter: MethodFilter, handler: H) -> Self
+    where
+        H: IntoEndpoint<B, E, T>,
+    {
+        self.routes.push(Endpoint {
+            filter,
+            guard: None,
+            route: handler.into_endpoint(),
+        });
+        self
+    }
+
+    /// Attach a [`Guard`] to the endpoint that was most recently added.
+    ///
+    /// The guard is evaluated, in addition to the method filter, before dispatching a request to
+    /// that endpoint. Endpoints are tried in registration order, so a guarded endpoint should
+    /// usually be registered before the catch-all one it falls back to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no endpoint has been registered yet.
+    pub fn guard<G>(mut self, guard: G) -> Self
+    where
+        G: Guard<B> + 'static,
+    {
+        let endpoint = self
+            .routes
+            .last_mut()
+            .expect("`guard` must be called after an endpoint (e.g. `get`, `on`) has been added");
+        endpoint.guard = Some(Arc::new(guard));
+        self
+    }
+}
+
+impl<B, E> Default for MethodRouter<B, E>
+where
+    B: Send + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, E> Service<Request<B>> for MethodRouter<B, E>
+where
+    B: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = E;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let method_filter = MethodFilter::from_method(req.method());
+        let parts = RequestParts::new(req);
+
+        let mut method_matched = false;
+        let mut matched_index = None;
+
+        for (idx, endpoint) in self.routes.iter().enumerate() {
+            if method_filter.map_or(false, |filter| endpoint.filter.contains(filter)) {
+                method_matched = true;
+                if endpoint
+                    .guard
+                    .as_ref()
+                    .map_or(true, |guard| guard.check(&parts))
+                {
+                    matched_index = Some(idx);
+                    break;
+                }
+            }
+        }
+
+        let req = parts
+            .try_into_request()
+            .expect("body wasn't extracted from `RequestParts`");
+
+        if let Some(idx) = matched_index {
+            self.routes[idx].route.call(req)
+        } else if method_matched {
+            Box::pin(std::future::ready(Ok(not_found())))
+        } else {
+            Box::pin(std::future::ready(Ok(method_not_allowed())))
+        }
+    }
+}
+
+fn method_not_allowed() -> Response<BoxBody> {
+    let mut res = Response::new(crate::body::empty());
+    *res.status_mut() = StatusCode::METHOD_NOT_ALLOWED;
+    res
+}
+
+fn not_found() -> Response<BoxBody> {
+    let mut res = Response::new(crate::body::empty());
+    *res.status_mut() = StatusCode::NOT_FOUND;
+    res
+}
+
+/// Route requests to [`Service`]s rather than [`Handler`]s.
+///
+/// See [`crate`] docs for an example of routing to fallible services.
+pub mod service_method_router {
+    use super::*;
+
+    macro_rules! top_level_service_method_fn {
+        ($name:ident, $filter:ident) => {
+            #[doc = concat!("Route `", stringify!($filter), "` requests to `service`.")]
+            pub fn $name<S, B>(service: S) -> MethodRouter<B, S::Error>
+            where
+                S: Service<Request<B>, Response = Response<BoxBody>> + Clone + Send + 'static,
+                S::Future: Send + 'static,
+                B: Send + 'static,
+            {
+                on(MethodFilter::$filter, service)
+            }
+        };
+    }
+
+    top_level_service_method_fn!(connect, CONNECT);
+    top_level_service_method_fn!(delete, DELETE);
+    top_level_service_method_fn!(get, GET);
+    top_level_service_method_fn!(head, HEAD);
+    top_level_service_method_fn!(options, OPTIONS);
+    top_level_service_method_fn!(patch, PATCH);
+    top_level_service_method_fn!(post, POST);
+    top_level_service_method_fn!(put, PUT);
+    top_level_service_method_fn!(trace, TRACE);
+
+    /// Route requests matching `filter` to `service`.
+    pub fn on<S, B>(filter: MethodFilter, service: S) -> MethodRouter<B, S::Error>
+    where
+        S: Service<Request<B>, Response = Response<BoxBody>> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+        B: Send + 'static,
+    {
+        MethodRouter::new().on(filter, service)
+    }
+
+    /// Route requests with any method to `service`.
+    pub fn any<S, B>(service: S) -> MethodRouter<B, S::Error>
+    where
+        S: Service<Request<B>, Response = Response<BoxBody>> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+        B: Send + 'static,
+    {
+        on(
+            MethodFilter::CONNECT
+                | MethodFilter::DELETE
+                | MethodFilter::GET
+                | MethodFilter::HEAD
+                | MethodFilter::OPTIONS
+                | MethodFilter::PATCH
+                | MethodFilter::POST
+                | MethodFilter::PUT
+                | MethodFilter::TRACE,
+            service,
+        )
+    }
+
+    /// Map the response body of a [`Service`] to [`BoxBody`] so it can be routed to directly.
+    pub fn box_response_body<S, B, ResBody>(service: S) -> BoxResponseBody<S>
+    where
+        S: Service<Request<B>, Response = Response<ResBody>>,
+        ResBody: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+        ResBody::Error: Into<BoxError>,
+    {
+        BoxResponseBody(service)
+    }
+}
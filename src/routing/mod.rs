@@ -0,0 +1,909 @@
+//! Routing between [`Service`]s and handlers.
+
+use crate::{
+    body::BoxBody,
+    extract::{MatchedPath, UrlParams},
+    response::IntoResponse,
+    util::try_downcast,
+    BoxError,
+};
+use http::{HeaderMap, Method, Request, Response, StatusCode, Uri};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+pub mod guard;
+
+mod method_routing;
+mod not_found;
+mod route;
+
+use self::{
+    method_routing::IntoEndpoint,
+    not_found::NotFound,
+    route::{from_service, lift_infallible, Route},
+};
+
+pub use self::method_routing::{
+    any, connect, delete, get, head, on, options, patch, post, put, service_method_router, trace,
+    MethodFilter, MethodRouter,
+};
+
+/// The parameter name used for the catch-all segment [`Router::nest`] generates when nesting a
+/// plain [`Service`], or a [`Router`] that has its own fallback.
+pub(crate) const NEST_TAIL_PARAM: &str = "axum_nest";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    Static(String),
+    Capture(String),
+    Wildcard(String),
+}
+
+#[derive(Clone, Debug)]
+struct PathMatcher {
+    source: String,
+    segments: Vec<Segment>,
+    trailing_slash: bool,
+}
+
+enum MatchOutcome {
+    None,
+    RedirectSlash,
+    Matched(Vec<(String, String)>),
+}
+
+impl PathMatcher {
+    fn new(path: &str) -> Self {
+        assert!(!path.is_empty(), "Invalid route: empty path");
+
+        let trailing_slash = path != "/" && path.ends_with('/');
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                if let Some(name) = segment.strip_prefix(':') {
+                    Segment::Capture(name.to_owned())
+                } else if let Some(name) = segment.strip_prefix('*') {
+                    Segment::Wildcard(name.to_owned())
+                } else {
+                    Segment::Static(segment.to_owned())
+                }
+            })
+            .collect();
+
+        Self {
+            source: path.to_owned(),
+            segments,
+            trailing_slash,
+        }
+    }
+
+    /// A score used to rank competing matches: lower is more specific. Used so that, e.g., a
+    /// static `/foo` segment wins over a capture `/:key` registered for the same position.
+    fn specificity(&self) -> Vec<u8> {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Static(_) => 0,
+                Segment::Capture(_) => 1,
+                Segment::Wildcard(_) => 2,
+            })
+            .collect()
+    }
+
+    /// Returns `true` if some request path could match both `self` and `other`, i.e. registering
+    /// both (without [`Router::allow_overlapping_routes`]) would make dispatch ambiguous.
+    fn conflicts_with(&self, other: &PathMatcher) -> bool {
+        let mut ours = self.segments.iter();
+        let mut theirs = other.segments.iter();
+
+        loop {
+            match (ours.next(), theirs.next()) {
+                (Some(Segment::Wildcard(_)), _) | (_, Some(Segment::Wildcard(_))) => {
+                    return true;
+                }
+                (Some(Segment::Static(a)), Some(Segment::Static(b))) => {
+                    if a != b {
+                        return false;
+                    }
+                }
+                (Some(_), Some(_)) => {}
+                (None, None) => {
+                    // Same segments, but `/foo` and `/foo/` never match the same concrete path.
+                    return self.trailing_slash == other.trailing_slash;
+                }
+                (None, Some(_)) | (Some(_), None) => return false,
+            }
+        }
+    }
+
+    fn matches(&self, path: &str) -> MatchOutcome {
+        let has_trailing_slash = path.len() > 1 && path.ends_with('/');
+        let mut path_segments = path.split('/').filter(|segment| !segment.is_empty());
+        let mut pattern_segments = self.segments.iter();
+        let mut captures = Vec::new();
+
+        loop {
+            match (pattern_segments.next(), path_segments.next()) {
+                (Some(Segment::Static(expected)), Some(actual)) => {
+                    if expected != actual {
+                        return MatchOutcome::None;
+                    }
+                }
+                (Some(Segment::Capture(name)), Some(actual)) => {
+                    captures.push((name.clone(), actual.to_owned()));
+                }
+                (Some(Segment::Wildcard(name)), Some(first)) => {
+                    let mut rest = vec![first];
+                    rest.extend(path_segments.by_ref());
+                    captures.push((name.clone(), format!("/{}", rest.join("/"))));
+                    return MatchOutcome::Matched(captures);
+                }
+                (Some(Segment::Wildcard(name)), None) => {
+                    return if has_trailing_slash || path == "/" {
+                        captures.push((name.clone(), "/".to_owned()));
+                        MatchOutcome::Matched(captures)
+                    } else {
+                        MatchOutcome::RedirectSlash
+                    };
+                }
+                (None, None) => {
+                    return if has_trailing_slash == self.trailing_slash {
+                        MatchOutcome::Matched(captures)
+                    } else {
+                        MatchOutcome::RedirectSlash
+                    };
+                }
+                (None, Some(_)) | (Some(_), None) => return MatchOutcome::None,
+            }
+        }
+    }
+}
+
+enum Endpoint<B, E = Infallible> {
+    MethodRouter(MethodRouter<B, E>),
+    Route(Route<B, E>),
+}
+
+impl<B, E> Clone for Endpoint<B, E> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::MethodRouter(method_router) => Self::MethodRouter(method_router.clone()),
+            Self::Route(route) => Self::Route(route.clone()),
+        }
+    }
+}
+
+impl<B, E> Service<Request<B>> for Endpoint<B, E>
+where
+    B: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = E;
+    type Future = <Route<B, E> as Service<Request<B>>>::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::MethodRouter(method_router) => method_router.poll_ready(cx),
+            Self::Route(route) => route.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        match self {
+            Self::MethodRouter(method_router) => method_router.call(req),
+            Self::Route(route) => route.call(req),
+        }
+    }
+}
+
+/// The router type used to compose handlers and services into one application.
+///
+/// `E` is the error type of the routes registered so far; it starts out as [`Infallible`] and
+/// only changes if a fallible [`Service`] is registered with [`route`](Router::route) or
+/// [`nest`](Router::nest), in which case [`handle_error`](Router::handle_error) can be used to
+/// turn it back into an infallible `Router` ready for [`into_make_service`](Router::into_make_service).
+///
+/// See [`crate`] docs for more details.
+pub struct Router<B = crate::body::Body, E = Infallible> {
+    routes: Vec<(PathMatcher, Endpoint<B, E>)>,
+    names: HashMap<Arc<str>, PathMatcher>,
+    fallback: Route<B>,
+    fallback_set: bool,
+    allow_overlapping_routes: bool,
+}
+
+impl<B, E> Clone for Router<B, E> {
+    fn clone(&self) -> Self {
+        Self {
+            routes: self.routes.clone(),
+            names: self.names.clone(),
+            fallback: self.fallback.clone(),
+            fallback_set: self.fallback_set,
+            allow_overlapping_routes: self.allow_overlapping_routes,
+        }
+    }
+}
+
+impl<B, E> fmt::Debug for Router<B, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Router").finish()
+    }
+}
+
+impl<B, E> Default for Router<B, E>
+where
+    B: Send + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, E> Router<B, E>
+where
+    B: Send + 'static,
+    E: 'static,
+{
+    /// Create a new `Router` that responds `404 Not Found` to all requests.
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            names: HashMap::new(),
+            fallback: from_service(NotFound),
+            fallback_set: false,
+            allow_overlapping_routes: false,
+        }
+    }
+
+    /// Add a route to the router pointing at `svc`, usually a [`MethodRouter`] created with
+    /// [`get`], [`post`], and friends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `path` is empty, if `svc` is a [`Router`] (use [`Router::nest`] instead), or if
+    /// `path`'s pattern overlaps with one already registered (e.g. `/users/:id` and
+    /// `/users/*rest`) and [`Router::allow_overlapping_routes`] hasn't been called.
+    pub fn route<T>(mut self, path: &str, svc: T) -> Self
+    where
+        T: Service<Request<B>, Response = Response<BoxBody>, Error = E> + Clone + Send + 'static,
+        T::Future: Send + 'static,
+    {
+        let svc = match try_downcast::<Self, _>(svc) {
+            Ok(_) => panic!(
+                "Invalid route: `Router::route` cannot be used with `Router`s. Use `Router::nest` instead"
+            ),
+            Err(svc) => svc,
+        };
+
+        let endpoint = match try_downcast::<MethodRouter<B, E>, _>(svc) {
+            Ok(method_router) => Endpoint::MethodRouter(method_router),
+            Err(svc) => Endpoint::Route(from_service(svc)),
+        };
+
+        let matcher = PathMatcher::new(path);
+
+        if !self.allow_overlapping_routes {
+            assert!(
+                !self.routes.iter().any(|(existing, _)| existing.conflicts_with(&matcher)),
+                "Overlapping route: `{}`. Call `Router::allow_overlapping_routes` to register \
+                 overlapping routes, with the most specific pattern winning at dispatch time",
+                path
+            );
+        }
+
+        self.routes.push((matcher, endpoint));
+        self
+    }
+
+    /// Allow routes registered with [`Router::route`] to overlap (e.g. a wildcard route alongside
+    /// a more specific one), instead of the default of panicking when a new route could match a
+    /// request an already-registered one also matches.
+    ///
+    /// With this enabled, a request that matches more than one route is dispatched to whichever
+    /// is most specific: static segments beat `:captures`, which beat `*wildcards`; ties keep
+    /// whichever route was registered first.
+    pub fn allow_overlapping_routes(mut self) -> Self {
+        self.allow_overlapping_routes = true;
+        self
+    }
+
+    /// Associate a name with the route most recently added by [`Router::route`], so it can later
+    /// be looked up with [`Router::url_for`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called before any route has been added, or if `name` is already in use.
+    pub fn name(mut self, name: &str) -> Self {
+        let matcher = self
+            .routes
+            .last()
+            .expect("`Router::name` was called before any route was added")
+            .0
+            .clone();
+
+        assert!(
+            !self.names.contains_key(name),
+            "route name `{}` is already in use",
+            name
+        );
+
+        self.names.insert(Arc::from(name), matcher);
+        self
+    }
+
+    /// Nest a [`Router`] or [`Service`] at `path`.
+    ///
+    /// Nesting a [`Router`] splices its routes directly into `self`, with `path` as a prefix, so
+    /// [`MatchedPath`] reports the combined pattern (e.g. `/api/users/:id`). If the nested
+    /// `Router` has its own [`fallback`](Self::fallback), it keeps handling unmatched requests
+    /// under `path` instead of falling through to `self`'s fallback. Nesting any other
+    /// [`Service`] forwards requests whose path starts with `path` to it, with the rest of the
+    /// path (including the leading `/`) left in the request's URI.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `path` is empty.
+    pub fn nest<T>(mut self, path: &str, svc: T) -> Self
+    where
+        T: Service<Request<B>, Response = Response<BoxBody>, Error = E> + Clone + Send + 'static,
+        T::Future: Send + 'static,
+    {
+        assert!(!path.is_empty(), "Invalid route: empty path");
+        let prefix = path.trim_end_matches('/');
+
+        match try_downcast::<Self, _>(svc) {
+            Ok(router) => {
+                let inner_fallback_set = router.fallback_set;
+                let inner_fallback = router.fallback;
+
+                for (matcher, endpoint) in router.routes {
+                    let prefixed = PathMatcher::new(&format!("{}{}", prefix, matcher.source));
+                    self.routes.push((prefixed, endpoint));
+                }
+
+                for (name, matcher) in router.names {
+                    let prefixed = PathMatcher::new(&format!("{}{}", prefix, matcher.source));
+                    assert!(
+                        self.names.insert(name.clone(), prefixed).is_none(),
+                        "route name `{}` is already in use",
+                        name
+                    );
+                }
+
+                // If the nested router customized its fallback, keep it as the least-specific
+                // match under this prefix so it still runs for paths that fall within the nest
+                // but don't hit one of its routes, rather than falling through to `self`'s own
+                // fallback.
+                if inner_fallback_set {
+                    let pattern = format!("{}/*{}", prefix, NEST_TAIL_PARAM);
+                    self.routes.push((
+                        PathMatcher::new(&pattern),
+                        Endpoint::Route(lift_infallible(inner_fallback)),
+                    ));
+                }
+
+                self
+            }
+            Err(svc) => {
+                let pattern = format!("{}/*{}", prefix, NEST_TAIL_PARAM);
+                let nested = StripPrefix::new(svc, prefix.len());
+                self.routes
+                    .push((PathMatcher::new(&pattern), Endpoint::Route(from_service(nested))));
+                self
+            }
+        }
+    }
+
+    /// Set the fallback [`Service`] (or [`Handler`](crate::handler::Handler)) that's called when a
+    /// request doesn't match any route.
+    ///
+    /// Replaces the default, which responds `404 Not Found` with an empty body.
+    pub fn fallback<T, K>(mut self, svc: T) -> Self
+    where
+        T: IntoEndpoint<B, Infallible, K>,
+    {
+        self.fallback = svc.into_endpoint();
+        self.fallback_set = true;
+        self
+    }
+
+    /// Merge the routes of `other` into `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both `self` and `other` have a custom [`fallback`](Self::fallback) set.
+    pub fn merge(mut self, other: Router<B, E>) -> Self {
+        self.allow_overlapping_routes |= other.allow_overlapping_routes;
+        self.routes.extend(other.routes);
+
+        for (name, matcher) in other.names {
+            assert!(
+                self.names.insert(name.clone(), matcher).is_none(),
+                "route name `{}` is already in use",
+                name
+            );
+        }
+
+        if other.fallback_set {
+            assert!(
+                !self.fallback_set,
+                "Cannot merge two `Router`s that both have a fallback"
+            );
+            self.fallback = other.fallback;
+            self.fallback_set = true;
+        }
+
+        self
+    }
+
+    /// Apply a [`tower::Layer`] to all routes (and the fallback) registered so far.
+    ///
+    /// Routes added after calling `layer` are not affected. Since the layer runs around the
+    /// already-matched route, extractors like [`MatchedPath`](crate::extract::MatchedPath) still
+    /// see the request as the router dispatched it.
+    pub fn layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route<B, E>> + Layer<Route<B>>,
+        <L as Layer<Route<B, E>>>::Service:
+            Service<Request<B>, Response = Response<BoxBody>, Error = E> + Clone + Send + 'static,
+        <<L as Layer<Route<B, E>>>::Service as Service<Request<B>>>::Future: Send + 'static,
+        <L as Layer<Route<B>>>::Service: Service<Request<B>, Response = Response<BoxBody>, Error = Infallible>
+            + Clone
+            + Send
+            + 'static,
+        <<L as Layer<Route<B>>>::Service as Service<Request<B>>>::Future: Send + 'static,
+    {
+        for (_, endpoint) in &mut self.routes {
+            let route = match endpoint {
+                Endpoint::MethodRouter(method_router) => from_service(method_router.clone()),
+                Endpoint::Route(route) => route.clone(),
+            };
+            *endpoint = Endpoint::Route(from_service(layer.layer(route)));
+        }
+
+        self.fallback = from_service(layer.layer(self.fallback));
+
+        self
+    }
+
+    /// Apply an error-handling closure to every route registered so far, turning this
+    /// `Router<B, E>` into a plain `Router<B>` ready to be served.
+    ///
+    /// The fallback is untouched, since it's always infallible already. `f` is called with the
+    /// error produced by a route and a cheap, owned [`RequestInfo`] snapshot of the request that
+    /// was being handled, captured before the route ran (and so still available even though the
+    /// route may have consumed the request's body). This is a router-wide alternative to wrapping
+    /// every fallible route in its own [`HandleErrorLayer`](crate::error_handling::HandleErrorLayer).
+    pub fn handle_error<F, Res>(self, f: F) -> Router<B>
+    where
+        F: Fn(BoxError, RequestInfo) -> Res + Clone + Send + Sync + 'static,
+        Res: IntoResponse,
+        E: Into<BoxError>,
+    {
+        let routes = self
+            .routes
+            .into_iter()
+            .map(|(matcher, endpoint)| {
+                let route: Route<B, E> = match endpoint {
+                    Endpoint::MethodRouter(method_router) => from_service(method_router),
+                    Endpoint::Route(route) => route,
+                };
+                let handled = from_service(HandleRouterError::new(route, f.clone()));
+                (matcher, Endpoint::Route(handled))
+            })
+            .collect();
+
+        Router {
+            routes,
+            names: self.names,
+            fallback: self.fallback,
+            fallback_set: self.fallback_set,
+            allow_overlapping_routes: self.allow_overlapping_routes,
+        }
+    }
+
+    /// Convert this router into a [`MakeService`](tower::make::MakeService), so it can be served
+    /// directly by [`hyper::Server`].
+    pub fn into_make_service(self) -> IntoMakeService<Self> {
+        IntoMakeService::new(self)
+    }
+
+    /// Build a [`Uri`] for the route registered under `name` with [`Router::name`], substituting
+    /// `params` for its `:capture`/`*wildcard` segments and percent-encoding their values.
+    ///
+    /// Reuses the same segment grammar [`Router::route`]'s path patterns are parsed with, so the
+    /// pattern and the generated URL can never drift out of sync.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` isn't registered, a captured/wildcard parameter is missing from
+    /// `params`, or `params` contains a name the pattern doesn't have.
+    pub fn url_for<I, K, V>(&self, name: &str, params: I) -> Result<Uri, UrlForError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: fmt::Display,
+    {
+        let matcher = self.names.get(name).ok_or(UrlForError::NoSuchRoute)?;
+
+        let mut params: Vec<(String, String)> = params
+            .into_iter()
+            .map(|(name, value)| (name.as_ref().to_owned(), value.to_string()))
+            .collect();
+
+        let mut path = String::new();
+        for segment in &matcher.segments {
+            path.push('/');
+            match segment {
+                Segment::Static(value) => path.push_str(value),
+                Segment::Capture(name) => {
+                    let value = take_param(&mut params, name)
+                        .ok_or_else(|| UrlForError::MissingParam(name.clone()))?;
+                    path.push_str(&percent_encode(&value));
+                }
+                Segment::Wildcard(name) => {
+                    let value = take_param(&mut params, name)
+                        .ok_or_else(|| UrlForError::MissingParam(name.clone()))?;
+                    let encoded = value
+                        .split('/')
+                        .map(percent_encode)
+                        .collect::<Vec<_>>()
+                        .join("/");
+                    path.push_str(&encoded);
+                }
+            }
+        }
+
+        if path.is_empty() {
+            path.push('/');
+        } else if matcher.trailing_slash {
+            path.push('/');
+        }
+
+        if let Some((name, _)) = params.into_iter().next() {
+            return Err(UrlForError::UnexpectedParam(name));
+        }
+
+        path.parse().map_err(UrlForError::InvalidUri)
+    }
+
+    fn find_match(&self, path: &str) -> Option<(usize, Vec<(String, String)>)> {
+        let mut best: Option<(usize, Vec<(String, String)>)> = None;
+
+        for (idx, (matcher, _)) in self.routes.iter().enumerate() {
+            if let MatchOutcome::Matched(captures) = matcher.matches(path) {
+                let is_better = match &best {
+                    Some((best_idx, _)) => {
+                        matcher.specificity() < self.routes[*best_idx].0.specificity()
+                    }
+                    None => true,
+                };
+                if is_better {
+                    best = Some((idx, captures));
+                }
+            }
+        }
+
+        best
+    }
+
+    fn redirect_slash(&self, path: &str) -> Option<String> {
+        self.routes
+            .iter()
+            .any(|(matcher, _)| matches!(matcher.matches(path), MatchOutcome::RedirectSlash))
+            .then(|| {
+                if path.len() > 1 && path.ends_with('/') {
+                    path.trim_end_matches('/').to_owned()
+                } else {
+                    format!("{}/", path)
+                }
+            })
+    }
+}
+
+impl<B, E> Service<Request<B>> for Router<B, E>
+where
+    B: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = E;
+    type Future = Pin<Box<dyn Future<Output = Result<Response<BoxBody>, E>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        let path = req.uri().path().to_owned();
+
+        if let Some((idx, captures)) = self.find_match(&path) {
+            let matcher = &self.routes[idx].0;
+            req.extensions_mut()
+                .insert(MatchedPath(Arc::from(matcher.source.as_str())));
+            req.extensions_mut().insert(UrlParams(captures));
+            return self.routes[idx].1.call(req);
+        }
+
+        if let Some(location) = self.redirect_slash(&path) {
+            let res = Response::builder()
+                .status(StatusCode::MOVED_PERMANENTLY)
+                .header(http::header::LOCATION, location)
+                .body(crate::body::empty())
+                .unwrap();
+            return Box::pin(std::future::ready(Ok(res)));
+        }
+
+        let fallback = self.fallback.call(req);
+        Box::pin(async move {
+            match fallback.await {
+                Ok(res) => Ok(res),
+                Err(never) => match never {},
+            }
+        })
+    }
+}
+
+fn take_param(params: &mut Vec<(String, String)>, name: &str) -> Option<String> {
+    let idx = params.iter().position(|(key, _)| key == name)?;
+    Some(params.remove(idx).1)
+}
+
+/// Percent-encode every byte of `value` that isn't in the URI "unreserved" set
+/// (`ALPHA / DIGIT / "-" / "." / "_" / "~"`, per RFC 3986).
+fn percent_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
+
+/// An error returned by [`Router::url_for`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UrlForError {
+    /// No route is registered under the given name.
+    NoSuchRoute,
+    /// The route's pattern has a `:capture`/`*wildcard` segment that wasn't supplied.
+    MissingParam(String),
+    /// A parameter was supplied that the route's pattern doesn't have.
+    UnexpectedParam(String),
+    /// The generated path wasn't a valid [`Uri`].
+    InvalidUri(http::uri::InvalidUri),
+}
+
+impl fmt::Display for UrlForError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSuchRoute => write!(f, "no route is registered under that name"),
+            Self::MissingParam(name) => write!(f, "missing parameter `{}`", name),
+            Self::UnexpectedParam(name) => write!(f, "unexpected parameter `{}`", name),
+            Self::InvalidUri(err) => write!(f, "generated an invalid URI: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for UrlForError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidUri(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A cheap, owned snapshot of a request, passed to a [`Router::handle_error`] closure alongside
+/// the error a route produced.
+///
+/// It's captured before the route runs, so it's still available even if handling the error
+/// requires inspecting the request after the route has consumed its body.
+#[derive(Clone, Debug)]
+pub struct RequestInfo {
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    matched_path: Option<Arc<str>>,
+}
+
+impl RequestInfo {
+    fn capture<B>(req: &Request<B>, matched_path: Option<Arc<str>>) -> Self {
+        Self {
+            method: req.method().clone(),
+            uri: req.uri().clone(),
+            headers: req.headers().clone(),
+            matched_path,
+        }
+    }
+
+    /// The request's method.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// The request's URI.
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    /// The request's headers.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The route pattern the request matched, if any. [`Router::handle_error`] only covers
+    /// matched routes (the router's own fallback is left untouched, and a nested router's
+    /// fallback is matched through a catch-all pattern under its prefix), so this is set for
+    /// every `RequestInfo` it passes to its closure.
+    pub fn matched_path(&self) -> Option<&str> {
+        self.matched_path.as_deref()
+    }
+}
+
+/// A [`Service`] adapter that turns a fallible route into an infallible one by mapping its error,
+/// together with a [`RequestInfo`] snapshot, through a closure. Used by [`Router::handle_error`].
+struct HandleRouterError<S, F> {
+    inner: S,
+    f: F,
+}
+
+impl<S, F> HandleRouterError<S, F> {
+    fn new(inner: S, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<S, F> Clone for HandleRouterError<S, F>
+where
+    S: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<S, F, B, Res> Service<Request<B>> for HandleRouterError<S, F>
+where
+    S: Service<Request<B>, Response = Response<BoxBody>> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+    F: Fn(BoxError, RequestInfo) -> Res + Clone + Send + 'static,
+    Res: IntoResponse,
+    B: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response<BoxBody>, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let matched_path = req.extensions().get::<MatchedPath>().map(|path| path.0.clone());
+        let info = RequestInfo::capture(&req, matched_path);
+
+        let f = self.f.clone();
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            match inner.call(req).await {
+                Ok(res) => Ok(res),
+                Err(err) => Ok(f(err.into(), info).into_response()),
+            }
+        })
+    }
+}
+
+/// A [`Service`] that strips a fixed-length prefix from the request's URI path before forwarding
+/// to `inner`. Used by [`Router::nest`] when nesting a plain [`Service`] rather than a [`Router`].
+struct StripPrefix<S> {
+    inner: S,
+    prefix_len: usize,
+}
+
+impl<S> StripPrefix<S> {
+    fn new(inner: S, prefix_len: usize) -> Self {
+        Self { inner, prefix_len }
+    }
+}
+
+impl<S: Clone> Clone for StripPrefix<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            prefix_len: self.prefix_len,
+        }
+    }
+}
+
+impl<S, B> Service<Request<B>> for StripPrefix<S>
+where
+    S: Service<Request<B>, Response = Response<BoxBody>, Error = Infallible>,
+{
+    type Response = Response<BoxBody>;
+    type Error = Infallible;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        let rest = &req.uri().path()[self.prefix_len..];
+        let rest = if rest.is_empty() { "/" } else { rest };
+
+        let mut parts = http::uri::Parts::default();
+        parts.path_and_query = Some(match req.uri().query() {
+            Some(query) => format!("{}?{}", rest, query).parse().unwrap(),
+            None => rest.parse().unwrap(),
+        });
+        parts.scheme = req.uri().scheme().cloned();
+        parts.authority = req.uri().authority().cloned();
+
+        *req.uri_mut() = Uri::from_parts(parts).unwrap();
+
+        self.inner.call(req)
+    }
+}
+
+/// A [`MakeService`](tower::make::MakeService) adapter produced by [`Router::into_make_service`]
+/// or [`HandlerWithoutStateExt::into_make_service`](crate::handler::HandlerWithoutStateExt::into_make_service).
+pub struct IntoMakeService<S> {
+    svc: S,
+}
+
+impl<S> IntoMakeService<S> {
+    pub(crate) fn new(svc: S) -> Self {
+        Self { svc }
+    }
+}
+
+impl<S> fmt::Debug for IntoMakeService<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoMakeService").finish()
+    }
+}
+
+impl<S: Clone> Clone for IntoMakeService<S> {
+    fn clone(&self) -> Self {
+        Self {
+            svc: self.svc.clone(),
+        }
+    }
+}
+
+impl<S, T> Service<T> for IntoMakeService<S>
+where
+    S: Clone,
+{
+    type Response = S;
+    type Error = Infallible;
+    type Future = std::future::Ready<Result<S, Infallible>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _target: T) -> Self::Future {
+        std::future::ready(Ok(self.svc.clone()))
+    }
+}
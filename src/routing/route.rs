@@ -0,0 +1,97 @@
+use crate::{
+    body::{box_body, BoxBody},
+    clone_box_service::CloneBoxService,
+    BoxError,
+};
+use http::{Request, Response};
+use std::{
+    convert::Infallible,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower_service::Service;
+
+/// A type-erased, cloneable [`Service`] that handles requests of type `Request<B>` and always
+/// produces a boxed response body, used internally to store the endpoints registered with a
+/// [`Router`](super::Router) or [`MethodRouter`](super::MethodRouter).
+pub(crate) type Route<B, E = Infallible> = CloneBoxService<Request<B>, Response<BoxBody>, E>;
+
+pub(crate) fn from_service<S, B>(svc: S) -> Route<B, S::Error>
+where
+    S: Service<Request<B>, Response = Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    Route::new(svc)
+}
+
+/// Widen an infallible [`Route`] into a `Route<B, E>`, so it can sit alongside fallible routes
+/// that share the error type `E`. There's no actual error to convert (the source is
+/// `Infallible`), so this is purely a type-level adapter.
+pub(crate) fn lift_infallible<B, E>(route: Route<B>) -> Route<B, E>
+where
+    B: 'static,
+    E: 'static,
+{
+    Route::new(LiftInfallible(route, PhantomData))
+}
+
+#[derive(Clone)]
+struct LiftInfallible<S, E>(S, PhantomData<fn() -> E>);
+
+impl<S, B, E> Service<Request<B>> for LiftInfallible<S, E>
+where
+    S: Service<Request<B>, Response = Response<BoxBody>, Error = Infallible> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = E;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.0.poll_ready(cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok(())),
+            Poll::Ready(Err(err)) => match err {},
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let fut = self.0.call(req);
+        Box::pin(async move {
+            match fut.await {
+                Ok(res) => Ok(res),
+                Err(err) => match err {},
+            }
+        })
+    }
+}
+
+/// Adapts a [`Service`] whose response body isn't already [`BoxBody`] so it can be stored
+/// alongside routes whose body is.
+#[derive(Clone)]
+pub(crate) struct BoxResponseBody<S>(pub(crate) S);
+
+impl<S, B, ResBody> Service<Request<B>> for BoxResponseBody<S>
+where
+    S: Service<Request<B>, Response = Response<ResBody>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: 'static,
+    ResBody: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+    ResBody::Error: Into<BoxError>,
+    B: 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> std::task::Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let fut = self.0.call(req);
+        Box::pin(async move { fut.await.map(|res| res.map(box_body)) })
+    }
+}
@@ -0,0 +1,18 @@
+//! Marking headers as sensitive, so they're scrubbed from request/response logging produced by
+//! things like [`tower_http`]'s `TraceLayer`.
+//!
+//! Two complementary ways to mark headers sensitive are provided:
+//!
+//! - [`SetSensitiveHeadersLayer`], re-exported from `tower-http`, wraps a whole [`Router`] (or any
+//!   other `tower::Service`) and marks the given header names sensitive on *both* the request and
+//!   the response, regardless of which handler produced the response.
+//! - [`response::Headers::sensitive`](crate::response::Headers::sensitive) marks header names
+//!   sensitive on a single handler's return value, without needing a layer at all.
+//!
+//! There's currently no axum-specific wrapper around `SetSensitiveHeadersLayer` — it's applied the
+//! same way as with any other tower service, e.g. `Router::layer(SetSensitiveHeadersLayer::new([...]))`.
+//!
+//! [`Router`]: crate::Router
+
+#[doc(inline)]
+pub use tower_http::sensitive_headers::SetSensitiveHeadersLayer;
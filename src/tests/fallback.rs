@@ -0,0 +1,72 @@
+use super::*;
+
+#[tokio::test]
+async fn default_fallback_is_404() {
+    let app = Router::new().route("/", get(|| async {}));
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/not-found").send().await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn custom_fallback_handles_unmatched_requests() {
+    let app = Router::new()
+        .route("/", get(|| async {}))
+        .fallback(|| async { (StatusCode::NOT_FOUND, "nothing to see here") });
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/").send().await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = client.get("/not-found").send().await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.text().await, "nothing to see here");
+}
+
+#[tokio::test]
+async fn fallback_still_runs_under_layer() {
+    let app = Router::new()
+        .route("/", get(|| async {}))
+        .fallback(|| async { StatusCode::IM_A_TEAPOT })
+        .layer(tower_http::compression::CompressionLayer::new());
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/not-found").send().await;
+    assert_eq!(res.status(), StatusCode::IM_A_TEAPOT);
+}
+
+#[tokio::test]
+async fn nested_fallback_handles_unmatched_requests_under_prefix() {
+    let api = Router::new()
+        .route("/users", get(|| async { "users" }))
+        .fallback(|| async { (StatusCode::NOT_FOUND, "no such api route") });
+
+    let app = Router::new().nest("/api", api);
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/api/users").send().await;
+    assert_eq!(res.text().await, "users");
+
+    let res = client.get("/api/nope").send().await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.text().await, "no such api route");
+
+    // Falls through to the outer router's own (default) fallback, since it's outside the prefix.
+    let res = client.get("/other").send().await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.text().await, "");
+}
+
+#[tokio::test]
+#[should_panic(expected = "Cannot merge two `Router`s that both have a fallback")]
+async fn merging_two_custom_fallbacks_panics() {
+    let one = Router::new().fallback(|| async { StatusCode::NOT_FOUND });
+    let two = Router::new().fallback(|| async { StatusCode::NOT_FOUND });
+
+    one.merge(two);
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::routing::{
+    guard::{header, host, Any, Guard},
+    MethodRouter,
+};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn guard_picks_between_endpoints_sharing_a_method() {
+    let app = Router::new().route(
+        "/",
+        get(|| async { "v2" })
+            .guard(header("x-api-version", "2"))
+            .on(MethodFilter::GET, || async { "default" }),
+    );
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/").header("x-api-version", "2").send().await;
+    assert_eq!(res.text().await, "v2");
+
+    let res = client.get("/").send().await;
+    assert_eq!(res.text().await, "default");
+}
+
+#[tokio::test]
+async fn guard_falls_through_to_unguarded_endpoint() {
+    let app = Router::new().route(
+        "/",
+        on(MethodFilter::GET, || async { "example.com" })
+            .guard(host("example.com"))
+            .on(MethodFilter::GET, || async { "fallback" }),
+    );
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/").header("host", "example.com").send().await;
+    assert_eq!(res.text().await, "example.com");
+
+    let res = client.get("/").header("host", "other.com").send().await;
+    assert_eq!(res.text().await, "fallback");
+}
+
+#[tokio::test]
+async fn any_combinator_requires_one_guard_to_pass() {
+    let guard = Any::new(vec![
+        Arc::new(header("x-admin", "true")) as Arc<dyn Guard<Body>>,
+        Arc::new(host("internal.example.com")) as Arc<dyn Guard<Body>>,
+    ]);
+
+    let app = Router::new().route("/", get(|| async { "ok" }).guard(guard));
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/").header("x-admin", "true").send().await;
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = client.get("/").send().await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+#[should_panic(expected = "`guard` must be called after an endpoint")]
+async fn guard_without_endpoint_panics() {
+    MethodRouter::<Body>::new().guard(header::<Body>("x-foo", "bar"));
+}
@@ -0,0 +1,73 @@
+use super::*;
+use std::{future::Future, io, pin::Pin};
+use tower::service_fn;
+
+type BoxFuture<E> = Pin<Box<dyn Future<Output = Result<Response<BoxBody>, E>> + Send>>;
+
+fn failing_route() -> impl Service<Request<Body>, Response = Response<BoxBody>, Error = io::Error, Future = BoxFuture<io::Error>>
+       + Clone {
+    service_fn(|_req: Request<Body>| {
+        Box::pin(async { Err(io::Error::new(io::ErrorKind::Other, "boom")) }) as BoxFuture<io::Error>
+    })
+}
+
+fn ok_route(
+    body: &'static str,
+) -> impl Service<Request<Body>, Response = Response<BoxBody>, Error = io::Error, Future = BoxFuture<io::Error>>
+       + Clone {
+    service_fn(move |_req: Request<Body>| {
+        Box::pin(async move { Ok(Response::new(crate::body::box_body(http_body::Full::from(body)))) })
+            as BoxFuture<io::Error>
+    })
+}
+
+#[tokio::test]
+async fn handle_error_turns_route_errors_into_responses() {
+    let app = Router::new()
+        .route("/", service::get(failing_route()))
+        .handle_error(|_err, _info| StatusCode::INTERNAL_SERVER_ERROR);
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/").send().await;
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn handle_error_leaves_the_fallback_untouched() {
+    let app = Router::new()
+        .route("/", service::get(failing_route()))
+        .handle_error(|_err, _info| StatusCode::INTERNAL_SERVER_ERROR);
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/not-found").send().await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+// Regression test: nesting a router with a custom fallback under a fallible, `handle_error`-bound
+// outer router used to fail to compile, since the nested fallback was always `Route<B, Infallible>`
+// but the outer router's routes are `Route<B, E>`.
+#[tokio::test]
+async fn nest_with_custom_fallback_under_fallible_router() {
+    let api = Router::new()
+        .route("/users", service::get(ok_route("users")))
+        .fallback(|| async { (StatusCode::NOT_FOUND, "no such api route") });
+
+    let app = Router::new()
+        .route("/boom", service::get(failing_route()))
+        .nest("/api", api)
+        .handle_error(|_err, _info| StatusCode::INTERNAL_SERVER_ERROR);
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/api/users").send().await;
+    assert_eq!(res.text().await, "users");
+
+    let res = client.get("/api/nope").send().await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.text().await, "no such api route");
+
+    let res = client.get("/boom").send().await;
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
@@ -33,12 +33,23 @@ use tower_service::Service;
 
 pub(crate) use helpers::*;
 
+#[cfg(feature = "auth")]
+mod auth;
 mod fallback;
 mod get_to_head;
+mod guard;
 mod handle_error;
 mod helpers;
 mod merge;
+#[cfg(feature = "json")]
+mod negotiate;
 mod nest;
+mod overlapping_routes;
+mod sensitive_headers;
+mod set_header;
+#[cfg(feature = "sse")]
+mod sse;
+mod url_for;
 
 #[tokio::test]
 async fn hello_world() {
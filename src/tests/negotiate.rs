@@ -0,0 +1,130 @@
+use super::*;
+use crate::{extract::AcceptedTypes, response::Negotiate};
+use serde::Serialize;
+
+#[derive(Serialize, Clone)]
+struct Greeting {
+    message: &'static str,
+}
+
+async fn handler(accepted: AcceptedTypes) -> Negotiate<Greeting> {
+    Negotiate::new(
+        Greeting {
+            message: "hello",
+        },
+        accepted,
+    )
+}
+
+#[tokio::test]
+async fn negotiate_responds_json_by_default() {
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/").send().await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["content-type"], "application/json");
+    assert_eq!(res.json::<Value>().await, json!({ "message": "hello" }));
+}
+
+#[tokio::test]
+async fn negotiate_respects_explicit_accept_header() {
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+
+    let res = client
+        .get("/")
+        .header("accept", "application/json")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn negotiate_406s_when_nothing_registered_is_acceptable() {
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+
+    let res = client
+        .get("/")
+        .header("accept", "application/xml")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::NOT_ACCEPTABLE);
+}
+
+#[tokio::test]
+async fn best_match_prefers_higher_q_value() {
+    let accepted = accepted_types("application/json;q=0.5, text/plain;q=0.9").await;
+    let json = mime::APPLICATION_JSON;
+    let text = mime::TEXT_PLAIN;
+
+    assert_eq!(accepted.best_match(&[&json, &text]), Some(&text));
+}
+
+#[tokio::test]
+async fn best_match_prefers_exact_over_wildcard() {
+    let accepted = accepted_types("*/*, application/json").await;
+    let json = mime::APPLICATION_JSON;
+    let text = mime::TEXT_PLAIN;
+
+    assert_eq!(accepted.best_match(&[&text, &json]), Some(&json));
+}
+
+#[tokio::test]
+async fn best_match_breaks_equal_ties_by_client_declaration_order() {
+    // Both ranges are equally qualified (q=1.0) and equally specific (exact match), so the tie
+    // should be broken by which one the client listed first in `Accept` — not by which one the
+    // server listed first in `offered`.
+    let accepted = accepted_types("text/plain, application/json").await;
+    let json = mime::APPLICATION_JSON;
+    let text = mime::TEXT_PLAIN;
+
+    // `offered` prefers `json` first, but the client declared `text/plain` first.
+    assert_eq!(accepted.best_match(&[&json, &text]), Some(&text));
+}
+
+#[tokio::test]
+#[cfg(feature = "cbor")]
+async fn negotiate_can_choose_cbor_when_the_feature_is_on() {
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+
+    let res = client
+        .get("/")
+        .header("accept", "application/cbor")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["content-type"], "application/cbor");
+}
+
+#[tokio::test]
+#[cfg(not(any(feature = "cbor", feature = "msgpack")))]
+async fn negotiate_only_offers_json_with_no_extra_formats_enabled() {
+    let app = Router::new().route("/", get(handler));
+
+    let client = TestClient::new(app);
+
+    let res = client
+        .get("/")
+        .header("accept", "application/cbor")
+        .send()
+        .await;
+    assert_eq!(res.status(), StatusCode::NOT_ACCEPTABLE);
+}
+
+async fn accepted_types(accept_header: &str) -> AcceptedTypes {
+    let req = Request::builder()
+        .header("accept", accept_header)
+        .body(Body::empty())
+        .unwrap();
+    let mut parts = extract::RequestParts::new(req);
+    extract::FromRequest::from_request(&mut parts)
+        .await
+        .unwrap()
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+#[tokio::test]
+#[should_panic(expected = "Overlapping route")]
+async fn overlapping_routes_panic_by_default() {
+    Router::new()
+        .route("/users/:id", get(|| async {}))
+        .route("/users/*rest", get(|| async {}));
+}
+
+#[tokio::test]
+async fn allow_overlapping_routes_permits_registration() {
+    let app = Router::new()
+        .allow_overlapping_routes()
+        .route("/users/:id", get(|| async { "capture" }))
+        .route("/users/*rest", get(|| async { "wildcard" }));
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/users/42").send().await;
+    assert_eq!(res.text().await, "capture");
+}
+
+#[tokio::test]
+async fn most_specific_overlapping_route_wins() {
+    let app = Router::new()
+        .allow_overlapping_routes()
+        .route("/users/*rest", get(|| async { "wildcard" }))
+        .route("/users/:id", get(|| async { "capture" }))
+        .route("/users/me", get(|| async { "static" }));
+
+    let client = TestClient::new(app);
+
+    assert_eq!(client.get("/users/me").send().await.text().await, "static");
+    assert_eq!(client.get("/users/42").send().await.text().await, "capture");
+    assert_eq!(
+        client.get("/users/42/posts").send().await.text().await,
+        "wildcard"
+    );
+}
+
+#[tokio::test]
+async fn merging_overlapping_flag_is_sticky() {
+    let one = Router::new().allow_overlapping_routes();
+    let two = Router::new().route("/accounts/:id", get(|| async {}));
+
+    // `one.allow_overlapping_routes` should carry over after merging, so routes added to the
+    // merged router can still overlap even though they're chained onto `two`'s side.
+    let app = one
+        .merge(two)
+        .route("/accounts/*rest", get(|| async {}));
+
+    TestClient::new(app);
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::response::Headers;
+use http::header::{AUTHORIZATION, SET_COOKIE};
+
+#[test]
+fn sensitive_marks_only_the_given_headers() {
+    let res = Headers(vec![
+        (AUTHORIZATION, "Bearer secret"),
+        (SET_COOKIE, "session=abc"),
+    ])
+    .sensitive([AUTHORIZATION])
+    .into_response();
+
+    assert!(res.headers()[AUTHORIZATION].is_sensitive());
+    assert!(!res.headers()[SET_COOKIE].is_sensitive());
+}
+
+#[test]
+fn sensitive_ignores_header_names_absent_from_the_response() {
+    // `sensitive` is only asked to mark `AUTHORIZATION`, which isn't actually present; this
+    // shouldn't panic or affect the headers that are.
+    let res = Headers(vec![(SET_COOKIE, "session=abc")])
+        .sensitive([AUTHORIZATION])
+        .into_response();
+
+    assert!(!res.headers()[SET_COOKIE].is_sensitive());
+}
+
+#[test]
+fn sensitive_marks_every_value_of_a_repeated_header_name() {
+    let res = Headers(vec![
+        (SET_COOKIE, "session=abc"),
+        (SET_COOKIE, "csrf=def"),
+    ])
+    .sensitive([SET_COOKIE])
+    .into_response();
+
+    let values = res.headers().get_all(SET_COOKIE).iter().collect::<Vec<_>>();
+    assert_eq!(values.len(), 2);
+    assert!(values.iter().all(|value| value.is_sensitive()));
+}
@@ -0,0 +1,73 @@
+use super::*;
+use crate::response::{Headers, SetResponseHeaderLayer};
+use http::header::{CONTENT_LENGTH, X_CONTENT_TYPE_OPTIONS};
+
+#[tokio::test]
+async fn overriding_replaces_any_existing_value() {
+    let app = Router::new()
+        .route(
+            "/",
+            get(|| async { Headers(vec![(CONTENT_LENGTH, "wrong")]) }),
+        )
+        .layer(SetResponseHeaderLayer::overriding(CONTENT_LENGTH, |_res| {
+            Some(http::HeaderValue::from_static("right"))
+        }));
+
+    let client = TestClient::new(app);
+    let res = client.get("/").send().await;
+
+    assert_eq!(res.headers()[CONTENT_LENGTH], "right");
+}
+
+#[tokio::test]
+async fn if_not_present_leaves_an_existing_value_alone() {
+    let app = Router::new()
+        .route(
+            "/",
+            get(|| async { Headers(vec![(X_CONTENT_TYPE_OPTIONS, "nosniff")]) }),
+        )
+        .layer(SetResponseHeaderLayer::if_not_present(
+            X_CONTENT_TYPE_OPTIONS,
+            |_res| Some(http::HeaderValue::from_static("should-not-appear")),
+        ));
+
+    let client = TestClient::new(app);
+    let res = client.get("/").send().await;
+
+    assert_eq!(res.headers()[X_CONTENT_TYPE_OPTIONS], "nosniff");
+}
+
+#[tokio::test]
+async fn if_not_present_sets_an_absent_header() {
+    let app = Router::new().route("/", get(|| async {})).layer(
+        SetResponseHeaderLayer::if_not_present(X_CONTENT_TYPE_OPTIONS, |_res| {
+            Some(http::HeaderValue::from_static("nosniff"))
+        }),
+    );
+
+    let client = TestClient::new(app);
+    let res = client.get("/").send().await;
+
+    assert_eq!(res.headers()[X_CONTENT_TYPE_OPTIONS], "nosniff");
+}
+
+#[tokio::test]
+async fn appending_keeps_the_existing_value_alongside_the_new_one() {
+    let app = Router::new()
+        .route("/", get(|| async { Headers(vec![("vary", "accept")]) }))
+        .layer(SetResponseHeaderLayer::appending(
+            http::header::VARY,
+            |_res| Some(http::HeaderValue::from_static("accept-language")),
+        ));
+
+    let client = TestClient::new(app);
+    let res = client.get("/").send().await;
+
+    let values = res
+        .headers()
+        .get_all(http::header::VARY)
+        .iter()
+        .map(|v| v.to_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec!["accept", "accept-language"]);
+}
@@ -0,0 +1,49 @@
+use super::*;
+use crate::response::sse::{Event, Sse};
+use futures_util::stream;
+use std::convert::Infallible;
+
+#[tokio::test]
+async fn sse_sets_event_stream_headers() {
+    async fn handler() -> Sse<impl futures_core::Stream<Item = Result<Event, Infallible>>> {
+        Sse::new(stream::once(async { Ok(Event::default().data("hi!")) }))
+    }
+
+    let app = Router::new().route("/sse", get(handler));
+
+    let client = TestClient::new(app);
+
+    let res = client.get("/sse").send().await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["content-type"], "text/event-stream");
+    assert_eq!(res.headers()["cache-control"], "no-cache");
+}
+
+#[tokio::test]
+async fn sse_encodes_event_fields_per_the_wire_format() {
+    async fn handler() -> Sse<impl futures_core::Stream<Item = Result<Event, Infallible>>> {
+        Sse::new(stream::once(async {
+            Ok(Event::default()
+                .event("update")
+                .data("line one\nline two")
+                .id("1"))
+        }))
+    }
+
+    let app = Router::new().route("/sse", get(handler));
+
+    let client = TestClient::new(app);
+    let res = client.get("/sse").send().await;
+    let body = res.text().await;
+
+    assert_eq!(
+        body,
+        "event:update\ndata:line one\ndata:line two\nid:1\n\n"
+    );
+}
+
+#[test]
+#[should_panic(expected = "SSE event name cannot contain newlines")]
+fn event_name_with_newline_panics() {
+    Event::default().event("bad\nname");
+}
@@ -0,0 +1,56 @@
+use super::*;
+use crate::routing::UrlForError;
+
+#[tokio::test]
+async fn url_for_substitutes_captures_and_wildcards() {
+    let app = Router::new()
+        .route("/users/:id/posts/*rest", get(|| async {}))
+        .name("user_post");
+
+    let url = app
+        .url_for("user_post", [("id", "42"), ("rest", "a/b")])
+        .unwrap();
+
+    assert_eq!(url.path(), "/users/42/posts/a/b");
+}
+
+#[tokio::test]
+async fn url_for_percent_encodes_param_values() {
+    let app = Router::new().route("/search/:q", get(|| async {})).name("search");
+
+    let url = app.url_for("search", [("q", "a b/c")]).unwrap();
+
+    assert_eq!(url.path(), "/search/a%20b%2Fc");
+}
+
+#[tokio::test]
+async fn url_for_unknown_name_errors() {
+    let app = Router::new().route("/", get(|| async {}));
+
+    let err = app.url_for("missing", std::iter::empty::<(&str, &str)>());
+    assert!(matches!(err, Err(UrlForError::NoSuchRoute)));
+}
+
+#[tokio::test]
+async fn url_for_missing_param_errors() {
+    let app = Router::new().route("/users/:id", get(|| async {})).name("user");
+
+    let err = app.url_for("user", std::iter::empty::<(&str, &str)>());
+    assert!(matches!(err, Err(UrlForError::MissingParam(name)) if name == "id"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "already in use")]
+async fn duplicate_route_name_panics() {
+    Router::new()
+        .route("/one", get(|| async {}))
+        .name("same")
+        .route("/two", get(|| async {}))
+        .name("same");
+}
+
+#[tokio::test]
+#[should_panic(expected = "`Router::name` was called before any route was added")]
+async fn naming_before_any_route_panics() {
+    Router::new().name("too_early");
+}
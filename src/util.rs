@@ -0,0 +1,18 @@
+use std::any::Any;
+
+/// Downcast `k` into `T` if the two types are the same, otherwise return `k` unchanged.
+///
+/// Used in a few places where we want to reuse a concrete type if the caller already has it,
+/// without forcing the public API to take `Any`.
+pub(crate) fn try_downcast<T, K>(k: K) -> Result<T, K>
+where
+    T: 'static,
+    K: Send + 'static,
+{
+    let mut k = Some(k);
+    if let Some(k) = <dyn Any>::downcast_mut::<Option<T>>(&mut k) {
+        Ok(k.take().unwrap())
+    } else {
+        Err(k.unwrap())
+    }
+}